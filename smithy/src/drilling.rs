@@ -0,0 +1,787 @@
+use crate::float;
+
+/// Calculates the length of the conical point of a drill, measured along its axis.
+///
+/// A depth programmed to the drill point leaves a hole that is only full diameter this
+/// far above the bottom:
+///
+/// ```markdown
+/// length = (dia / 2) / tan(point angle / 2)
+/// ```
+///
+/// # Parameters
+/// - dia: Diameter of the drill, in inches.
+/// - point_angle_deg: Included point angle of the drill in degrees (typically 118° or 135°).
+///
+/// # Returns
+/// - `f64`: Length of the point, in inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::drilling::drill_point_length;
+/// assert!((drill_point_length(0.5, 118.0) - 0.1502).abs() < 0.0001);
+/// ```
+pub fn drill_point_length(dia: f64, point_angle_deg: f64) -> f64 {
+    (dia / 2.0) / float::tan((point_angle_deg / 2.0).to_radians())
+}
+
+/// Calculates the Z travel needed to drill a through hole at full diameter.
+///
+/// The drill point has to pass right through the material, plus a margin so the full
+/// diameter clears the far side cleanly:
+///
+/// ```markdown
+/// depth = thickness + (dia / 2) / tan(point angle / 2) + breakthrough
+/// ```
+///
+/// # Parameters
+/// - material_thickness: Thickness of the material, in inches.
+/// - dia: Diameter of the drill, in inches.
+/// - point_angle_deg: Included point angle of the drill in degrees.
+/// - breakthrough: Extra travel beyond the point length, in inches.
+///
+/// # Returns
+/// - `f64`: Depth to program, measured to the drill point from the top surface, in
+///   inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::drilling::through_hole_depth;
+/// let depth = through_hole_depth(0.75, 0.5, 118.0, 0.05);
+/// assert!((depth - 0.9502).abs() < 0.0001);
+/// ```
+pub fn through_hole_depth(
+    material_thickness: f64,
+    dia: f64,
+    point_angle_deg: f64,
+    breakthrough: f64,
+) -> f64 {
+    material_thickness + drill_point_length(dia, point_angle_deg) + breakthrough
+}
+
+/// Calculates the minimum drill depth for a blind tapped hole.
+///
+/// A tap cannot cut full threads over its chamfered lead, and the drill leaves a cone at
+/// the bottom of the hole, so the drilled depth (measured to the drill point) must be:
+///
+/// ```markdown
+/// depth = usable thread length + chamfer threads × P + (drill / 2) / tan(point angle / 2)
+/// ```
+///
+/// # Parameters
+/// - usable_thread_len: Length of full-form thread required, in inches.
+/// - tpi: Threads per inch of the tap.
+/// - tap_chamfer_threads: Number of chamfered threads on the tap (taper ≈ 8-10,
+///   plug ≈ 3-5, bottoming ≈ 1-2).
+/// - drill: Diameter of the tap drill, in inches.
+/// - point_angle_deg: Included point angle of the drill in degrees (typically 118° or 135°).
+///
+/// # Returns
+/// - `f64`: Depth to drill, measured to the drill point, in inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::drilling::min_drill_depth_for_threads;
+/// let depth = min_drill_depth_for_threads(0.5, 20, 3, 0.201, 118.0);
+/// assert!(depth > 0.65);
+/// ```
+pub fn min_drill_depth_for_threads(
+    usable_thread_len: f64,
+    tpi: u32,
+    tap_chamfer_threads: u32,
+    drill: f64,
+    point_angle_deg: f64,
+) -> f64 {
+    let p = 1.0 / tpi as f64;
+    usable_thread_len + tap_chamfer_threads as f64 * p + drill_point_length(drill, point_angle_deg)
+}
+
+/// Calculates how deep to plunge a countersink to reach a top diameter.
+///
+/// The depth is measured from where the cone meets the pilot hole, so with no pilot
+/// hole it is the depth of the countersink point:
+///
+/// ```markdown
+/// depth = (top diameter - pilot diameter) / (2 × tan(angle / 2))
+/// ```
+///
+/// # Parameters
+/// - top_dia: Diameter of the countersink at the surface, in inches.
+/// - pilot_dia: Diameter of the hole being countersunk, or 0 for none, in inches.
+/// - included_angle_deg: Included angle of the countersink in degrees (82° for inch
+///   flat-head screws, 90° for metric).
+///
+/// # Returns
+/// - `f64`: Plunge depth below the surface, in inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::drilling::countersink_depth;
+/// let depth = countersink_depth(0.5, 0.25, 82.0);
+/// assert!((depth - 0.1438).abs() < 0.0001);
+/// ```
+pub fn countersink_depth(top_dia: f64, pilot_dia: f64, included_angle_deg: f64) -> f64 {
+    (top_dia - pilot_dia) / (2.0 * float::tan((included_angle_deg / 2.0).to_radians()))
+}
+
+/// Calculates the top diameter a countersink reaches at a plunge depth.
+///
+/// This is the inverse of `countersink_depth`:
+///
+/// ```markdown
+/// top diameter = pilot diameter + 2 × depth × tan(angle / 2)
+/// ```
+///
+/// # Parameters
+/// - depth: Plunge depth below the surface, measured from where the cone meets the
+///   pilot hole, in inches.
+/// - pilot_dia: Diameter of the hole being countersunk, or 0 for none, in inches.
+/// - included_angle_deg: Included angle of the countersink in degrees.
+///
+/// # Returns
+/// - `f64`: Diameter of the countersink at the surface, in inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::drilling::countersink_diameter;
+/// assert!((countersink_diameter(0.25, 0.0, 90.0) - 0.5).abs() < 1e-9);
+/// ```
+pub fn countersink_diameter(depth: f64, pilot_dia: f64, included_angle_deg: f64) -> f64 {
+    pilot_dia + 2.0 * depth * float::tan((included_angle_deg / 2.0).to_radians())
+}
+
+/// Calculates the cumulative depths of a peck drilling cycle.
+///
+/// Each peck is `reduction` shorter than the one before until it reaches `min_peck`,
+/// after which the pecks stay constant, as in a variable-peck G83 cycle. The last peck
+/// is shortened so the final depth is exactly `total_depth`.
+///
+/// # Parameters
+/// - total_depth: Depth of the hole below the start of the first peck.
+/// - first_peck: Depth of the first peck.
+/// - reduction: Amount each peck is shortened by.
+/// - min_peck: Shortest peck; pecks are never reduced below this.
+///
+/// # Returns
+/// - `Vec<f64>`: Depth reached by each peck, ending at `total_depth`. A hole no deeper
+///   than the first peck, or a peck that isn't positive, gives a single entry.
+///
+/// # Example
+/// ```rust
+/// use smithy::drilling::calc_peck_depths;
+/// let pecks = calc_peck_depths(0.45, 0.2, 0.05, 0.1);
+/// assert_eq!(pecks.len(), 3);
+/// assert_eq!(pecks[2], 0.45);
+/// ```
+#[cfg(feature = "std")]
+pub fn calc_peck_depths(
+    total_depth: f64,
+    first_peck: f64,
+    reduction: f64,
+    min_peck: f64,
+) -> Vec<f64> {
+    // Allow for round-off so a peck that lands on the bottom doesn't add a sliver peck.
+    const EPS: f64 = 1e-9;
+    let mut depths = Vec::new();
+    let mut depth = 0.0;
+    let mut peck = first_peck;
+    if peck > 0.0 {
+        while depth + peck < total_depth - EPS {
+            depth += peck;
+            depths.push(depth);
+            peck = (peck - reduction).max(min_peck);
+            if peck <= 0.0 {
+                break;
+            }
+        }
+    }
+    depths.push(total_depth);
+    depths
+}
+
+/// A standard drill size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrillSize {
+    /// Designation as marked on the drill, e.g. "#7", "F", "1/4\"" or "5.1 mm".
+    pub designation: &'static str,
+    /// Diameter in inches.
+    pub dia: f64,
+}
+
+/// Sets of standard drills to choose from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrillSet {
+    /// Number drills, #80 (0.0135") to #1 (0.228").
+    Number,
+    /// Letter drills, A (0.234") to Z (0.413").
+    Letter,
+    /// Fractional drills in 64ths up to 1".
+    Fractional,
+    /// Metric drills from 1.0 to 13.0 mm.
+    Metric,
+    /// Every size above.
+    All,
+}
+
+const fn drill(designation: &'static str, dia: f64) -> DrillSize {
+    DrillSize { designation, dia }
+}
+
+/// Number drills #80 to #1, in inches.
+const NUMBER_DRILLS: [DrillSize; 80] = [
+    drill("#80", 0.0135),
+    drill("#79", 0.0145),
+    drill("#78", 0.0160),
+    drill("#77", 0.0180),
+    drill("#76", 0.0200),
+    drill("#75", 0.0210),
+    drill("#74", 0.0225),
+    drill("#73", 0.0240),
+    drill("#72", 0.0250),
+    drill("#71", 0.0260),
+    drill("#70", 0.0280),
+    drill("#69", 0.0292),
+    drill("#68", 0.0310),
+    drill("#67", 0.0320),
+    drill("#66", 0.0330),
+    drill("#65", 0.0350),
+    drill("#64", 0.0360),
+    drill("#63", 0.0370),
+    drill("#62", 0.0380),
+    drill("#61", 0.0390),
+    drill("#60", 0.0400),
+    drill("#59", 0.0410),
+    drill("#58", 0.0420),
+    drill("#57", 0.0430),
+    drill("#56", 0.0465),
+    drill("#55", 0.0520),
+    drill("#54", 0.0550),
+    drill("#53", 0.0595),
+    drill("#52", 0.0635),
+    drill("#51", 0.0670),
+    drill("#50", 0.0700),
+    drill("#49", 0.0730),
+    drill("#48", 0.0760),
+    drill("#47", 0.0785),
+    drill("#46", 0.0810),
+    drill("#45", 0.0820),
+    drill("#44", 0.0860),
+    drill("#43", 0.0890),
+    drill("#42", 0.0935),
+    drill("#41", 0.0960),
+    drill("#40", 0.0980),
+    drill("#39", 0.0995),
+    drill("#38", 0.1015),
+    drill("#37", 0.1040),
+    drill("#36", 0.1065),
+    drill("#35", 0.1100),
+    drill("#34", 0.1110),
+    drill("#33", 0.1130),
+    drill("#32", 0.1160),
+    drill("#31", 0.1200),
+    drill("#30", 0.1285),
+    drill("#29", 0.1360),
+    drill("#28", 0.1405),
+    drill("#27", 0.1440),
+    drill("#26", 0.1470),
+    drill("#25", 0.1495),
+    drill("#24", 0.1520),
+    drill("#23", 0.1540),
+    drill("#22", 0.1570),
+    drill("#21", 0.1590),
+    drill("#20", 0.1610),
+    drill("#19", 0.1660),
+    drill("#18", 0.1695),
+    drill("#17", 0.1730),
+    drill("#16", 0.1770),
+    drill("#15", 0.1800),
+    drill("#14", 0.1820),
+    drill("#13", 0.1850),
+    drill("#12", 0.1890),
+    drill("#11", 0.1910),
+    drill("#10", 0.1935),
+    drill("#9", 0.1960),
+    drill("#8", 0.1990),
+    drill("#7", 0.2010),
+    drill("#6", 0.2040),
+    drill("#5", 0.2055),
+    drill("#4", 0.2090),
+    drill("#3", 0.2130),
+    drill("#2", 0.2210),
+    drill("#1", 0.2280),
+];
+
+/// Letter drills A to Z, in inches.
+const LETTER_DRILLS: [DrillSize; 26] = [
+    drill("A", 0.234),
+    drill("B", 0.238),
+    drill("C", 0.242),
+    drill("D", 0.246),
+    drill("E", 0.250),
+    drill("F", 0.257),
+    drill("G", 0.261),
+    drill("H", 0.266),
+    drill("I", 0.272),
+    drill("J", 0.277),
+    drill("K", 0.281),
+    drill("L", 0.290),
+    drill("M", 0.295),
+    drill("N", 0.302),
+    drill("O", 0.316),
+    drill("P", 0.323),
+    drill("Q", 0.332),
+    drill("R", 0.339),
+    drill("S", 0.348),
+    drill("T", 0.358),
+    drill("U", 0.368),
+    drill("V", 0.377),
+    drill("W", 0.386),
+    drill("X", 0.397),
+    drill("Y", 0.404),
+    drill("Z", 0.413),
+];
+
+/// Fractional drills 1/64" to 1" in 64ths.
+const FRACTIONAL_DRILLS: [DrillSize; 64] = [
+    drill("1/64\"", 1.0 / 64.0),
+    drill("1/32\"", 1.0 / 32.0),
+    drill("3/64\"", 3.0 / 64.0),
+    drill("1/16\"", 1.0 / 16.0),
+    drill("5/64\"", 5.0 / 64.0),
+    drill("3/32\"", 3.0 / 32.0),
+    drill("7/64\"", 7.0 / 64.0),
+    drill("1/8\"", 1.0 / 8.0),
+    drill("9/64\"", 9.0 / 64.0),
+    drill("5/32\"", 5.0 / 32.0),
+    drill("11/64\"", 11.0 / 64.0),
+    drill("3/16\"", 3.0 / 16.0),
+    drill("13/64\"", 13.0 / 64.0),
+    drill("7/32\"", 7.0 / 32.0),
+    drill("15/64\"", 15.0 / 64.0),
+    drill("1/4\"", 1.0 / 4.0),
+    drill("17/64\"", 17.0 / 64.0),
+    drill("9/32\"", 9.0 / 32.0),
+    drill("19/64\"", 19.0 / 64.0),
+    drill("5/16\"", 5.0 / 16.0),
+    drill("21/64\"", 21.0 / 64.0),
+    drill("11/32\"", 11.0 / 32.0),
+    drill("23/64\"", 23.0 / 64.0),
+    drill("3/8\"", 3.0 / 8.0),
+    drill("25/64\"", 25.0 / 64.0),
+    drill("13/32\"", 13.0 / 32.0),
+    drill("27/64\"", 27.0 / 64.0),
+    drill("7/16\"", 7.0 / 16.0),
+    drill("29/64\"", 29.0 / 64.0),
+    drill("15/32\"", 15.0 / 32.0),
+    drill("31/64\"", 31.0 / 64.0),
+    drill("1/2\"", 1.0 / 2.0),
+    drill("33/64\"", 33.0 / 64.0),
+    drill("17/32\"", 17.0 / 32.0),
+    drill("35/64\"", 35.0 / 64.0),
+    drill("9/16\"", 9.0 / 16.0),
+    drill("37/64\"", 37.0 / 64.0),
+    drill("19/32\"", 19.0 / 32.0),
+    drill("39/64\"", 39.0 / 64.0),
+    drill("5/8\"", 5.0 / 8.0),
+    drill("41/64\"", 41.0 / 64.0),
+    drill("21/32\"", 21.0 / 32.0),
+    drill("43/64\"", 43.0 / 64.0),
+    drill("11/16\"", 11.0 / 16.0),
+    drill("45/64\"", 45.0 / 64.0),
+    drill("23/32\"", 23.0 / 32.0),
+    drill("47/64\"", 47.0 / 64.0),
+    drill("3/4\"", 3.0 / 4.0),
+    drill("49/64\"", 49.0 / 64.0),
+    drill("25/32\"", 25.0 / 32.0),
+    drill("51/64\"", 51.0 / 64.0),
+    drill("13/16\"", 13.0 / 16.0),
+    drill("53/64\"", 53.0 / 64.0),
+    drill("27/32\"", 27.0 / 32.0),
+    drill("55/64\"", 55.0 / 64.0),
+    drill("7/8\"", 7.0 / 8.0),
+    drill("57/64\"", 57.0 / 64.0),
+    drill("29/32\"", 29.0 / 32.0),
+    drill("59/64\"", 59.0 / 64.0),
+    drill("15/16\"", 15.0 / 16.0),
+    drill("61/64\"", 61.0 / 64.0),
+    drill("31/32\"", 31.0 / 32.0),
+    drill("63/64\"", 63.0 / 64.0),
+    drill("1\"", 1.0),
+];
+
+/// Metric drills 1.0 to 10.0 mm in 0.1 mm steps, then to 13.0 mm in 0.5 mm steps.
+const METRIC_DRILLS: [DrillSize; 97] = [
+    drill("1.0 mm", 1.0 / 25.4),
+    drill("1.1 mm", 1.1 / 25.4),
+    drill("1.2 mm", 1.2 / 25.4),
+    drill("1.3 mm", 1.3 / 25.4),
+    drill("1.4 mm", 1.4 / 25.4),
+    drill("1.5 mm", 1.5 / 25.4),
+    drill("1.6 mm", 1.6 / 25.4),
+    drill("1.7 mm", 1.7 / 25.4),
+    drill("1.8 mm", 1.8 / 25.4),
+    drill("1.9 mm", 1.9 / 25.4),
+    drill("2.0 mm", 2.0 / 25.4),
+    drill("2.1 mm", 2.1 / 25.4),
+    drill("2.2 mm", 2.2 / 25.4),
+    drill("2.3 mm", 2.3 / 25.4),
+    drill("2.4 mm", 2.4 / 25.4),
+    drill("2.5 mm", 2.5 / 25.4),
+    drill("2.6 mm", 2.6 / 25.4),
+    drill("2.7 mm", 2.7 / 25.4),
+    drill("2.8 mm", 2.8 / 25.4),
+    drill("2.9 mm", 2.9 / 25.4),
+    drill("3.0 mm", 3.0 / 25.4),
+    drill("3.1 mm", 3.1 / 25.4),
+    drill("3.2 mm", 3.2 / 25.4),
+    drill("3.3 mm", 3.3 / 25.4),
+    drill("3.4 mm", 3.4 / 25.4),
+    drill("3.5 mm", 3.5 / 25.4),
+    drill("3.6 mm", 3.6 / 25.4),
+    drill("3.7 mm", 3.7 / 25.4),
+    drill("3.8 mm", 3.8 / 25.4),
+    drill("3.9 mm", 3.9 / 25.4),
+    drill("4.0 mm", 4.0 / 25.4),
+    drill("4.1 mm", 4.1 / 25.4),
+    drill("4.2 mm", 4.2 / 25.4),
+    drill("4.3 mm", 4.3 / 25.4),
+    drill("4.4 mm", 4.4 / 25.4),
+    drill("4.5 mm", 4.5 / 25.4),
+    drill("4.6 mm", 4.6 / 25.4),
+    drill("4.7 mm", 4.7 / 25.4),
+    drill("4.8 mm", 4.8 / 25.4),
+    drill("4.9 mm", 4.9 / 25.4),
+    drill("5.0 mm", 5.0 / 25.4),
+    drill("5.1 mm", 5.1 / 25.4),
+    drill("5.2 mm", 5.2 / 25.4),
+    drill("5.3 mm", 5.3 / 25.4),
+    drill("5.4 mm", 5.4 / 25.4),
+    drill("5.5 mm", 5.5 / 25.4),
+    drill("5.6 mm", 5.6 / 25.4),
+    drill("5.7 mm", 5.7 / 25.4),
+    drill("5.8 mm", 5.8 / 25.4),
+    drill("5.9 mm", 5.9 / 25.4),
+    drill("6.0 mm", 6.0 / 25.4),
+    drill("6.1 mm", 6.1 / 25.4),
+    drill("6.2 mm", 6.2 / 25.4),
+    drill("6.3 mm", 6.3 / 25.4),
+    drill("6.4 mm", 6.4 / 25.4),
+    drill("6.5 mm", 6.5 / 25.4),
+    drill("6.6 mm", 6.6 / 25.4),
+    drill("6.7 mm", 6.7 / 25.4),
+    drill("6.8 mm", 6.8 / 25.4),
+    drill("6.9 mm", 6.9 / 25.4),
+    drill("7.0 mm", 7.0 / 25.4),
+    drill("7.1 mm", 7.1 / 25.4),
+    drill("7.2 mm", 7.2 / 25.4),
+    drill("7.3 mm", 7.3 / 25.4),
+    drill("7.4 mm", 7.4 / 25.4),
+    drill("7.5 mm", 7.5 / 25.4),
+    drill("7.6 mm", 7.6 / 25.4),
+    drill("7.7 mm", 7.7 / 25.4),
+    drill("7.8 mm", 7.8 / 25.4),
+    drill("7.9 mm", 7.9 / 25.4),
+    drill("8.0 mm", 8.0 / 25.4),
+    drill("8.1 mm", 8.1 / 25.4),
+    drill("8.2 mm", 8.2 / 25.4),
+    drill("8.3 mm", 8.3 / 25.4),
+    drill("8.4 mm", 8.4 / 25.4),
+    drill("8.5 mm", 8.5 / 25.4),
+    drill("8.6 mm", 8.6 / 25.4),
+    drill("8.7 mm", 8.7 / 25.4),
+    drill("8.8 mm", 8.8 / 25.4),
+    drill("8.9 mm", 8.9 / 25.4),
+    drill("9.0 mm", 9.0 / 25.4),
+    drill("9.1 mm", 9.1 / 25.4),
+    drill("9.2 mm", 9.2 / 25.4),
+    drill("9.3 mm", 9.3 / 25.4),
+    drill("9.4 mm", 9.4 / 25.4),
+    drill("9.5 mm", 9.5 / 25.4),
+    drill("9.6 mm", 9.6 / 25.4),
+    drill("9.7 mm", 9.7 / 25.4),
+    drill("9.8 mm", 9.8 / 25.4),
+    drill("9.9 mm", 9.9 / 25.4),
+    drill("10.0 mm", 10.0 / 25.4),
+    drill("10.5 mm", 10.5 / 25.4),
+    drill("11.0 mm", 11.0 / 25.4),
+    drill("11.5 mm", 11.5 / 25.4),
+    drill("12.0 mm", 12.0 / 25.4),
+    drill("12.5 mm", 12.5 / 25.4),
+    drill("13.0 mm", 13.0 / 25.4),
+];
+
+fn drill_set(set: DrillSet) -> impl Iterator<Item = &'static DrillSize> {
+    let tables: [&'static [DrillSize]; 4] = match set {
+        DrillSet::Number => [&NUMBER_DRILLS, &[], &[], &[]],
+        DrillSet::Letter => [&LETTER_DRILLS, &[], &[], &[]],
+        DrillSet::Fractional => [&FRACTIONAL_DRILLS, &[], &[], &[]],
+        DrillSet::Metric => [&METRIC_DRILLS, &[], &[], &[]],
+        DrillSet::All => [
+            &NUMBER_DRILLS,
+            &LETTER_DRILLS,
+            &FRACTIONAL_DRILLS,
+            &METRIC_DRILLS,
+        ],
+    };
+    tables.into_iter().flatten()
+}
+
+/// Finds the smallest standard drill that is not smaller than a diameter.
+///
+/// Rounding up is the safe choice for tap and clearance drills: the hole can only
+/// come out slightly loose, never undersize. Use `closest_drill` to allow a drill
+/// that is slightly smaller.
+///
+/// # Parameters
+/// - dia: The required diameter, in inches.
+/// - set: The drills to choose from.
+///
+/// # Returns
+/// - `Option<DrillSize>`: The drill, or `None` if `dia` is larger than every drill in
+///   the set.
+///
+/// # Example
+/// ```rust
+/// use smithy::drilling::{nearest_drill, DrillSet};
+/// let drill = nearest_drill(0.2013, DrillSet::All).unwrap();
+/// assert_eq!(drill.designation, "13/64\"");
+/// ```
+pub fn nearest_drill(dia: f64, set: DrillSet) -> Option<DrillSize> {
+    // Allow for round-off in a computed diameter that matches a drill exactly.
+    const EPS: f64 = 1e-9;
+    drill_set(set)
+        .filter(|d| d.dia >= dia - EPS)
+        .min_by(|a, b| a.dia.total_cmp(&b.dia))
+        .copied()
+}
+
+/// Finds the standard drill closest to a diameter, whether larger or smaller.
+///
+/// # Parameters
+/// - dia: The required diameter, in inches.
+/// - set: The drills to choose from.
+///
+/// # Returns
+/// - `DrillSize`: The drill with the smallest difference in diameter.
+///
+/// # Example
+/// ```rust
+/// use smithy::drilling::{closest_drill, DrillSet};
+/// assert_eq!(closest_drill(0.2013, DrillSet::Number).designation, "#7");
+/// ```
+pub fn closest_drill(dia: f64, set: DrillSet) -> DrillSize {
+    *drill_set(set)
+        .min_by(|a, b| (a.dia - dia).abs().total_cmp(&(b.dia - dia).abs()))
+        .unwrap()
+}
+
+/// How closely a clearance hole fits its bolt or screw.
+///
+/// - Close: For accurate location, when the parts are aligned by the fasteners.
+/// - Normal: The usual choice for general assembly.
+/// - Loose: Allows for hole position error, e.g. parts drilled separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClearanceFit {
+    Close,
+    Normal,
+    Loose,
+}
+
+/// Clearance hole diameters from ASME B18.2.8, in inches, as
+/// (nominal size, close, normal, loose).
+const CLEARANCE_HOLES: [(f64, f64, f64, f64); 10] = [
+    (0.190, 0.201, 0.213, 15.0 / 64.0),
+    (0.25, 17.0 / 64.0, 9.0 / 32.0, 11.0 / 32.0),
+    (0.3125, 21.0 / 64.0, 11.0 / 32.0, 13.0 / 32.0),
+    (0.375, 25.0 / 64.0, 13.0 / 32.0, 15.0 / 32.0),
+    (0.4375, 29.0 / 64.0, 15.0 / 32.0, 17.0 / 32.0),
+    (0.5, 33.0 / 64.0, 17.0 / 32.0, 21.0 / 32.0),
+    (0.625, 21.0 / 32.0, 11.0 / 16.0, 25.0 / 32.0),
+    (0.75, 25.0 / 32.0, 13.0 / 16.0, 29.0 / 32.0),
+    (0.875, 29.0 / 32.0, 15.0 / 16.0, 33.0 / 32.0),
+    (1.0, 33.0 / 32.0, 17.0 / 16.0, 37.0 / 32.0),
+];
+
+/// Looks up the clearance hole diameter for a bolt or screw.
+///
+/// The holes come from the ASME B18.2.8 table for inch fasteners, #10 to 1":
+///
+/// ```markdown
+/// size     close     normal    loose
+/// #10      0.201     0.213     15/64
+/// 1/4"     17/64     9/32      11/32
+/// 5/16"    21/64     11/32     13/32
+/// 3/8"     25/64     13/32     15/32
+/// 7/16"    29/64     15/32     17/32
+/// 1/2"     33/64     17/32     21/32
+/// 5/8"     21/32     11/16     25/32
+/// 3/4"     25/32     13/16     29/32
+/// 7/8"     29/32     15/16     1-1/32
+/// 1"       1-1/32    1-1/16    1-5/32
+/// ```
+///
+/// A diameter within 0.0005" of a nominal size matches it, so `0.19` finds #10.
+///
+/// # Parameters
+/// - major_dia: Nominal (major) diameter of the fastener, in inches.
+/// - fit: How closely the hole fits the fastener.
+///
+/// # Returns
+/// - `Option<f64>`: Clearance hole diameter, in inches, or `None` if the size is not in
+///   the table. Pass it to `nearest_drill` for a standard drill that is not undersize.
+///
+/// # Example
+/// ```rust
+/// use smithy::drilling::{clearance_drill, nearest_drill, ClearanceFit, DrillSet};
+/// let dia = clearance_drill(0.25, ClearanceFit::Close).unwrap();
+/// assert_eq!(nearest_drill(dia, DrillSet::All).unwrap().designation, "17/64\"");
+/// ```
+pub fn clearance_drill(major_dia: f64, fit: ClearanceFit) -> Option<f64> {
+    CLEARANCE_HOLES
+        .iter()
+        .find(|(size, ..)| (size - major_dia).abs() < 0.0005)
+        .map(|&(_, close, normal, loose)| match fit {
+            ClearanceFit::Close => close,
+            ClearanceFit::Normal => normal,
+            ClearanceFit::Loose => loose,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math;
+
+    #[test]
+    fn test_min_drill_depth_for_threads() {
+        // 1/4-20 plug tap, #7 drill: 0.5 + 3 × 0.05 + 0.1005 / tan(59°)
+        let depth = min_drill_depth_for_threads(0.5, 20, 3, 0.201, 118.0);
+        assert_eq!(math::round(depth, 4), 0.7104);
+
+        // A bottoming tap needs less clearance than the plug tap.
+        let bottoming = min_drill_depth_for_threads(0.5, 20, 1, 0.201, 118.0);
+        assert_eq!(math::round(depth - bottoming, 4), 0.1);
+    }
+
+    #[test]
+    fn test_drill_point_length() {
+        assert_eq!(math::round(drill_point_length(0.5, 118.0), 4), 0.1502);
+        assert_eq!(math::round(drill_point_length(0.5, 135.0), 4), 0.1036);
+
+        let depth = through_hole_depth(0.75, 0.5, 118.0, 0.05);
+        assert_eq!(math::round(depth, 4), 0.9502);
+        // A flatter point needs less travel to break through.
+        assert!(through_hole_depth(0.75, 0.5, 135.0, 0.05) < depth);
+    }
+
+    #[test]
+    fn test_countersink() {
+        // 82° countersink to 0.5" over a 0.25" hole and with no pilot.
+        let depth = countersink_depth(0.5, 0.25, 82.0);
+        assert_eq!(math::round(depth, 4), 0.1438);
+        assert_eq!(math::round(countersink_depth(0.5, 0.0, 82.0), 4), 0.2876);
+        assert_eq!(math::round(countersink_diameter(depth, 0.25, 82.0), 9), 0.5);
+
+        // A 90° countersink is as deep as its radius.
+        assert_eq!(math::round(countersink_depth(0.5, 0.0, 90.0), 9), 0.25);
+    }
+
+    #[test]
+    fn test_clearance_drill() {
+        // 1/4" bolt: 0.266", 0.281" and 0.344".
+        assert_eq!(clearance_drill(0.25, ClearanceFit::Close), Some(0.265625));
+        assert_eq!(clearance_drill(0.25, ClearanceFit::Normal), Some(0.28125));
+        assert_eq!(clearance_drill(0.25, ClearanceFit::Loose), Some(0.34375));
+        let drill = |fit| {
+            nearest_drill(clearance_drill(0.25, fit).unwrap(), DrillSet::All)
+                .unwrap()
+                .designation
+        };
+        assert_eq!(drill(ClearanceFit::Close), "17/64\"");
+        assert_eq!(drill(ClearanceFit::Normal), "9/32\"");
+        assert_eq!(drill(ClearanceFit::Loose), "11/32\"");
+
+        // #10 has its own row, not a fraction of an inch over the size.
+        assert_eq!(clearance_drill(0.19, ClearanceFit::Close), Some(0.201));
+        assert_eq!(clearance_drill(0.19, ClearanceFit::Normal), Some(0.213));
+        assert_eq!(clearance_drill(0.19, ClearanceFit::Loose), Some(0.234375));
+
+        // 1/2": 33/64", 17/32" and 21/32".
+        assert_eq!(clearance_drill(0.5, ClearanceFit::Close), Some(0.515625));
+        assert_eq!(clearance_drill(0.5, ClearanceFit::Normal), Some(0.53125));
+        assert_eq!(clearance_drill(0.5, ClearanceFit::Loose), Some(0.65625));
+
+        // 1": 1-1/32", 1-1/16" and 1-5/32".
+        assert_eq!(clearance_drill(1.0, ClearanceFit::Close), Some(1.03125));
+        assert_eq!(clearance_drill(1.0, ClearanceFit::Normal), Some(1.0625));
+        assert_eq!(clearance_drill(1.0, ClearanceFit::Loose), Some(1.15625));
+
+        // Sizes outside the table.
+        assert_eq!(clearance_drill(0.3, ClearanceFit::Normal), None);
+        assert_eq!(clearance_drill(1.5, ClearanceFit::Normal), None);
+    }
+
+    #[test]
+    fn test_nearest_drill() {
+        let drill = nearest_drill(0.199, DrillSet::Number).unwrap();
+        assert_eq!(drill.designation, "#8");
+        assert_eq!(drill.dia, 0.199);
+        // Never undersize: just over #8 rounds up to #7.
+        assert_eq!(
+            nearest_drill(0.1991, DrillSet::Number).unwrap().designation,
+            "#7"
+        );
+        assert_eq!(closest_drill(0.1991, DrillSet::Number).designation, "#8");
+
+        let drill = nearest_drill(5.1 / 25.4, DrillSet::Metric).unwrap();
+        assert_eq!(drill.designation, "5.1 mm");
+        assert_eq!(
+            nearest_drill(5.12 / 25.4, DrillSet::Metric)
+                .unwrap()
+                .designation,
+            "5.2 mm"
+        );
+        assert_eq!(
+            nearest_drill(10.2 / 25.4, DrillSet::Metric)
+                .unwrap()
+                .designation,
+            "10.5 mm"
+        );
+
+        assert_eq!(
+            nearest_drill(0.25, DrillSet::Letter).unwrap().designation,
+            "E"
+        );
+        assert_eq!(
+            nearest_drill(0.26, DrillSet::Fractional)
+                .unwrap()
+                .designation,
+            "17/64\""
+        );
+        assert_eq!(
+            nearest_drill(0.2, DrillSet::All).unwrap().designation,
+            "5.1 mm"
+        );
+        assert!(nearest_drill(0.25, DrillSet::Number).is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_calc_peck_depths() {
+        let actual = calc_peck_depths(1.0, 0.2, 0.05, 0.1)
+            .iter()
+            .map(|d| math::round(*d, 6))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            vec![0.2, 0.35, 0.45, 0.55, 0.65, 0.75, 0.85, 0.95, 1.0]
+        );
+        assert_eq!(*calc_peck_depths(1.0, 0.2, 0.05, 0.1).last().unwrap(), 1.0);
+
+        // Exact multiple of the peck: no sliver peck at the bottom.
+        assert_eq!(calc_peck_depths(0.6, 0.2, 0.0, 0.2).len(), 3);
+
+        assert_eq!(calc_peck_depths(0.15, 0.2, 0.05, 0.1), vec![0.15]);
+        assert_eq!(calc_peck_depths(0.5, 0.0, 0.05, 0.0), vec![0.5]);
+    }
+}
@@ -0,0 +1,318 @@
+/// Upper limits (mm) of the ISO 286 nominal size ranges. Each range runs from the
+/// previous limit, exclusive, up to its own, inclusive.
+const SIZE_RANGES: [f64; 13] = [
+    3.0, 6.0, 10.0, 18.0, 30.0, 50.0, 80.0, 120.0, 180.0, 250.0, 315.0, 400.0, 500.0,
+];
+
+/// Standard tolerance grades IT5 to IT16 (µm) for each size range, from ISO 286-1
+/// Table 1.
+const IT_GRADES: [[f64; 12]; 13] = [
+    [
+        4.0, 6.0, 10.0, 14.0, 25.0, 40.0, 60.0, 100.0, 140.0, 250.0, 400.0, 600.0,
+    ],
+    [
+        5.0, 8.0, 12.0, 18.0, 30.0, 48.0, 75.0, 120.0, 180.0, 300.0, 480.0, 750.0,
+    ],
+    [
+        6.0, 9.0, 15.0, 22.0, 36.0, 58.0, 90.0, 150.0, 220.0, 360.0, 580.0, 900.0,
+    ],
+    [
+        8.0, 11.0, 18.0, 27.0, 43.0, 70.0, 110.0, 180.0, 270.0, 430.0, 700.0, 1100.0,
+    ],
+    [
+        9.0, 13.0, 21.0, 33.0, 52.0, 84.0, 130.0, 210.0, 330.0, 520.0, 840.0, 1300.0,
+    ],
+    [
+        11.0, 16.0, 25.0, 39.0, 62.0, 100.0, 160.0, 250.0, 390.0, 620.0, 1000.0, 1600.0,
+    ],
+    [
+        13.0, 19.0, 30.0, 46.0, 74.0, 120.0, 190.0, 300.0, 460.0, 740.0, 1200.0, 1900.0,
+    ],
+    [
+        15.0, 22.0, 35.0, 54.0, 87.0, 140.0, 220.0, 350.0, 540.0, 870.0, 1400.0, 2200.0,
+    ],
+    [
+        18.0, 25.0, 40.0, 63.0, 100.0, 160.0, 250.0, 400.0, 630.0, 1000.0, 1600.0, 2500.0,
+    ],
+    [
+        20.0, 29.0, 46.0, 72.0, 115.0, 185.0, 290.0, 460.0, 720.0, 1150.0, 1850.0, 2900.0,
+    ],
+    [
+        23.0, 32.0, 52.0, 81.0, 130.0, 210.0, 320.0, 520.0, 810.0, 1300.0, 2100.0, 3200.0,
+    ],
+    [
+        25.0, 36.0, 57.0, 89.0, 140.0, 230.0, 360.0, 570.0, 890.0, 1400.0, 2300.0, 3600.0,
+    ],
+    [
+        27.0, 40.0, 63.0, 97.0, 155.0, 250.0, 400.0, 630.0, 970.0, 1550.0, 2500.0, 4000.0,
+    ],
+];
+
+/// Lowest standard tolerance grade in `IT_GRADES`.
+const MIN_GRADE: u32 = 5;
+
+/// Fundamental deviations (µm) of the shaft zones for each size range, from ISO 286-1
+/// Table 2. For d to g this is the upper deviation es, for k to n the lower deviation ei.
+const SHAFT_D: [f64; 13] = [
+    -20.0, -30.0, -40.0, -50.0, -65.0, -80.0, -100.0, -120.0, -145.0, -170.0, -190.0, -210.0,
+    -230.0,
+];
+const SHAFT_E: [f64; 13] = [
+    -14.0, -20.0, -25.0, -32.0, -40.0, -50.0, -60.0, -72.0, -85.0, -100.0, -110.0, -125.0, -135.0,
+];
+const SHAFT_F: [f64; 13] = [
+    -6.0, -10.0, -13.0, -16.0, -20.0, -25.0, -30.0, -36.0, -43.0, -50.0, -56.0, -62.0, -68.0,
+];
+const SHAFT_G: [f64; 13] = [
+    -2.0, -4.0, -5.0, -6.0, -7.0, -9.0, -10.0, -12.0, -14.0, -15.0, -17.0, -18.0, -20.0,
+];
+/// Lower deviation of k for grades 4 to 7; other grades have ei = 0.
+const SHAFT_K: [f64; 13] = [
+    0.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 4.0, 5.0,
+];
+const SHAFT_M: [f64; 13] = [
+    2.0, 4.0, 6.0, 7.0, 8.0, 9.0, 11.0, 13.0, 15.0, 17.0, 20.0, 21.0, 23.0,
+];
+const SHAFT_N: [f64; 13] = [
+    4.0, 8.0, 10.0, 12.0, 15.0, 17.0, 20.0, 23.0, 27.0, 31.0, 34.0, 37.0, 40.0,
+];
+
+/// Upper and lower deviations of a tolerance zone from the basic size, in mm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ToleranceBand {
+    pub upper: f64,
+    pub lower: f64,
+}
+
+/// The result of pairing a hole and shaft tolerance zone.
+///
+/// Clearances are in mm; a negative clearance is an interference. A fit with both
+/// values positive is a clearance fit, both negative an interference fit, and mixed
+/// signs a transition fit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fit {
+    pub hole: ToleranceBand,
+    pub shaft: ToleranceBand,
+    pub min_clearance: f64,
+    pub max_clearance: f64,
+}
+
+/// Index of the ISO 286 size range containing `size`, or None outside 0-500 mm.
+fn range_index(size: f64) -> Option<usize> {
+    if size <= 0.0 {
+        return None;
+    }
+    SIZE_RANGES.iter().position(|hi| size <= *hi)
+}
+
+/// Standard tolerance IT5 to IT16 in µm for size range `r`.
+fn it_grade(r: usize, grade: u32) -> Option<f64> {
+    let i = grade.checked_sub(MIN_GRADE)? as usize;
+    IT_GRADES[r].get(i).copied()
+}
+
+/// Half-width (µm) of a js/JS zone. Odd tolerances of grades 7 to 11 are first rounded
+/// down to the even value below, so the deviations stay in whole micrometres.
+fn js_half(it: f64, grade: u32) -> f64 {
+    if (7..=11).contains(&grade) && it % 2.0 == 1.0 {
+        (it - 1.0) / 2.0
+    } else {
+        it / 2.0
+    }
+}
+
+/// Splits a designation such as "H7" or "js6" into its letters and grade.
+fn parse_zone(zone: &str) -> Option<(&str, u32)> {
+    let split = zone.find(|c: char| c.is_ascii_digit())?;
+    let (letters, grade) = zone.split_at(split);
+    Some((letters, grade.parse().ok()?))
+}
+
+/// Deviations (µm) of a shaft tolerance zone, as (upper, lower).
+fn shaft_band(r: usize, letters: &str, grade: u32) -> Option<(f64, f64)> {
+    let it = it_grade(r, grade)?;
+    let band = match letters {
+        "d" => upper_band(SHAFT_D[r], it),
+        "e" => upper_band(SHAFT_E[r], it),
+        "f" => upper_band(SHAFT_F[r], it),
+        "g" => upper_band(SHAFT_G[r], it),
+        "h" => upper_band(0.0, it),
+        "js" => (js_half(it, grade), -js_half(it, grade)),
+        "k" => {
+            let ei = match grade {
+                4..=7 => SHAFT_K[r],
+                _ => 0.0,
+            };
+            (ei + it, ei)
+        }
+        "m" => (SHAFT_M[r] + it, SHAFT_M[r]),
+        "n" => (SHAFT_N[r] + it, SHAFT_N[r]),
+        _ => return None,
+    };
+    Some(band)
+}
+
+/// Band for a zone whose fundamental deviation is its upper deviation.
+fn upper_band(es: f64, it: f64) -> (f64, f64) {
+    (es, es - it)
+}
+
+/// Deviations (µm) of a hole tolerance zone, as (upper, lower).
+fn hole_band(r: usize, letters: &str, grade: u32) -> Option<(f64, f64)> {
+    let it = it_grade(r, grade)?;
+    // Holes D to H mirror the matching shaft deviations about the basic size: EI = -es.
+    let shaft = match letters {
+        "JS" => return Some((js_half(it, grade), -js_half(it, grade))),
+        "D" => "d",
+        "E" => "e",
+        "F" => "f",
+        "G" => "g",
+        "H" => "h",
+        _ => return None,
+    };
+    let (es, _) = shaft_band(r, shaft, grade)?;
+    Some((-es + it, -es))
+}
+
+/// Calculates an ISO 286 hole/shaft fit.
+///
+/// The standard tolerance and fundamental deviation of each zone are looked up in the
+/// ISO 286-1 tables for the size range containing `basic_size`, and the limits follow
+/// from them:
+///
+/// ```markdown
+/// shafts d to h: es = fundamental deviation, ei = es - IT
+/// shafts k to n: ei = fundamental deviation, es = ei + IT
+/// holes D to H:  EI = -es of the matching shaft, ES = EI + IT
+/// ```
+///
+/// Supported hole zones are D, E, F, G, H and JS; supported shaft zones are d, e, f, g,
+/// h, js, k, m and n, each with grades 5 to 16.
+///
+/// # Parameters
+/// - basic_size: The basic (nominal) size of the mating parts, in mm, up to 500.
+/// - hole: Hole tolerance zone, e.g. "H7".
+/// - shaft: Shaft tolerance zone, e.g. "g6".
+///
+/// # Returns
+/// - `Option<Fit>`: The tolerance bands and clearance range in mm, or None when the
+///   size is out of range or a zone is not supported.
+///
+/// # Example
+/// ```rust
+/// use smithy::fits::iso_fit;
+/// let fit = iso_fit(25.0, "H7", "g6").unwrap();
+/// assert!(fit.min_clearance > 0.0);
+/// ```
+pub fn iso_fit(basic_size: f64, hole: &str, shaft: &str) -> Option<Fit> {
+    let r = range_index(basic_size)?;
+    let (letters, grade) = parse_zone(hole)?;
+    let (hole_es, hole_ei) = hole_band(r, letters, grade)?;
+    let (letters, grade) = parse_zone(shaft)?;
+    let (shaft_es, shaft_ei) = shaft_band(r, letters, grade)?;
+
+    Some(Fit {
+        hole: ToleranceBand {
+            upper: hole_es / 1000.0,
+            lower: hole_ei / 1000.0,
+        },
+        shaft: ToleranceBand {
+            upper: shaft_es / 1000.0,
+            lower: shaft_ei / 1000.0,
+        },
+        min_clearance: (hole_ei - shaft_es) / 1000.0,
+        max_clearance: (hole_es - shaft_ei) / 1000.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso_fit_clearance() {
+        let fit = iso_fit(25.0, "H7", "g6").unwrap();
+        assert_eq!(
+            fit.hole,
+            ToleranceBand {
+                upper: 0.021,
+                lower: 0.0
+            }
+        );
+        assert_eq!(
+            fit.shaft,
+            ToleranceBand {
+                upper: -0.007,
+                lower: -0.020
+            }
+        );
+        assert_eq!(fit.min_clearance, 0.007);
+        assert_eq!(fit.max_clearance, 0.041);
+    }
+
+    #[test]
+    fn test_iso_fit_transition() {
+        // H7/n6 at 25 mm: shaft +0.015/+0.028
+        let fit = iso_fit(25.0, "H7", "n6").unwrap();
+        assert_eq!(
+            fit.shaft,
+            ToleranceBand {
+                upper: 0.028,
+                lower: 0.015
+            }
+        );
+        assert_eq!(fit.min_clearance, -0.028);
+        assert_eq!(fit.max_clearance, 0.006);
+    }
+
+    /// Limits (µm) of a zone at a size, as listed in ISO 286-2.
+    fn limits(size: f64, zone: &str) -> (f64, f64) {
+        let (letters, grade) = parse_zone(zone).unwrap();
+        let r = range_index(size).unwrap();
+        if letters.starts_with(|c: char| c.is_ascii_uppercase()) {
+            hole_band(r, letters, grade).unwrap()
+        } else {
+            shaft_band(r, letters, grade).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_iso_tables() {
+        assert_eq!(limits(2.0, "H7"), (10.0, 0.0));
+        assert_eq!(limits(2.0, "g6"), (-2.0, -8.0));
+        assert_eq!(limits(8.0, "js7"), (7.0, -7.0));
+        assert_eq!(limits(12.0, "m6"), (18.0, 7.0));
+        assert_eq!(limits(25.0, "H12"), (210.0, 0.0));
+        assert_eq!(limits(25.0, "js6"), (6.5, -6.5));
+        assert_eq!(limits(25.0, "JS7"), (10.0, -10.0));
+        assert_eq!(limits(50.0, "f7"), (-25.0, -50.0));
+        assert_eq!(limits(50.0, "H8"), (39.0, 0.0));
+        assert_eq!(limits(100.0, "H9"), (87.0, 0.0));
+        assert_eq!(limits(100.0, "d9"), (-120.0, -207.0));
+        assert_eq!(limits(150.0, "F8"), (106.0, 43.0));
+        assert_eq!(limits(200.0, "k6"), (33.0, 4.0));
+        assert_eq!(limits(200.0, "k8"), (72.0, 0.0));
+        assert_eq!(limits(300.0, "n6"), (66.0, 34.0));
+        assert_eq!(limits(400.0, "E9"), (265.0, 125.0));
+        assert_eq!(limits(450.0, "e8"), (-135.0, -232.0));
+    }
+
+    #[test]
+    fn test_iso_fit_small_size() {
+        let fit = iso_fit(2.0, "H7", "g6").unwrap();
+        assert_eq!(fit.hole.upper, 0.010);
+        assert_eq!(fit.shaft.upper, -0.002);
+        assert_eq!(fit.min_clearance, 0.002);
+        assert_eq!(fit.max_clearance, 0.018);
+    }
+
+    #[test]
+    fn test_iso_fit_unsupported() {
+        assert!(iso_fit(25.0, "Z7", "g6").is_none());
+        assert!(iso_fit(25.0, "H", "g6").is_none());
+        assert!(iso_fit(600.0, "H7", "g6").is_none());
+        assert!(iso_fit(0.0, "H7", "g6").is_none());
+        assert!(iso_fit(25.0, "H4", "g6").is_none());
+        assert!(iso_fit(25.0, "H7", "g17").is_none());
+    }
+}
@@ -0,0 +1,71 @@
+//! Elementary float functions that work with or without `std`.
+//!
+//! `core` does not provide `sin`, `sqrt`, `powi` and friends, so under `no_std` they are
+//! routed to `libm`. With the `std` feature enabled the inherent `f64` methods are used.
+
+macro_rules! float_fn {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[cfg(feature = "std")]
+            #[inline]
+            pub(crate) fn $name(x: f64) -> f64 {
+                x.$name()
+            }
+
+            #[cfg(not(feature = "std"))]
+            #[inline]
+            pub(crate) fn $name(x: f64) -> f64 {
+                libm::$name(x)
+            }
+        )*
+    };
+}
+
+float_fn!(sin, cos, tan, sqrt, cbrt, round, trunc, floor, ceil);
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn powi(x: f64, n: i32) -> f64 {
+    x.powi(n)
+}
+
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn powi(x: f64, n: i32) -> f64 {
+    libm::pow(x, n as f64)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn powf(x: f64, n: f64) -> f64 {
+    x.powf(n)
+}
+
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn powf(x: f64, n: f64) -> f64 {
+    libm::pow(x, n)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    y.atan2(x)
+}
+
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    libm::atan2(y, x)
+}
+
+/// Euclidean remainder, always in `0..m` for a positive `m`; only needs `core`.
+#[inline]
+pub(crate) fn rem_euclid(x: f64, m: f64) -> f64 {
+    let r = x % m;
+    if r < 0.0 {
+        r + m
+    } else {
+        r
+    }
+}
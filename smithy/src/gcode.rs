@@ -0,0 +1,215 @@
+use crate::layout::{cart_to_polar, Coord};
+
+/// The canned cycle used by `drill_program`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DrillCycle {
+    /// G81 simple drilling: one feed to depth and a rapid retract.
+    G81,
+    /// G83 peck drilling, retracting to the R plane after each peck of `peck` depth.
+    G83 { peck: f64 },
+}
+
+/// Settings for a canned-cycle drilling program.
+///
+/// Lengths are in program units and the feed is per minute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrillOpts {
+    /// Retract (R) plane the tool rapids to above each hole.
+    pub r_plane: f64,
+    /// Final Z depth of the holes, reached by the drill point. For through holes see
+    /// `drilling::through_hole_depth`.
+    pub depth: f64,
+    pub feed: f64,
+    pub cycle: DrillCycle,
+}
+
+/// Emits an ISO G-code drilling program that drills every point.
+///
+/// The cycle is defined on the first hole, each further hole is a bare `X Y` line, and
+/// the cycle is cancelled with G80. Coordinates are absolute (G90) and the tool returns
+/// to the initial plane between holes (G98). The `angle` and `z` of the points are
+/// ignored; the depth comes from `opts`.
+///
+/// # Parameters
+/// - coords: Hole positions, in drilling order.
+/// - opts: Cycle, retract plane, depth and feed.
+///
+/// # Returns
+/// - `String`: The program, one block per line, or an empty string if there are no
+///   points.
+///
+/// # Example
+/// ```rust
+/// use smithy::gcode::{drill_program, DrillCycle, DrillOpts};
+/// use smithy::layout::calc_bolt_circle;
+/// let opts = DrillOpts { r_plane: 0.1, depth: -0.5, feed: 5.0, cycle: DrillCycle::G81 };
+/// let program = drill_program(calc_bolt_circle(2.0, 4, None, None, None, None), opts);
+/// assert_eq!(program.lines().count(), 6);
+/// assert!(program.ends_with("G80\n"));
+/// ```
+pub fn drill_program(coords: impl Iterator<Item = Coord>, opts: DrillOpts) -> String {
+    let mut program = String::new();
+    for (i, c) in coords.enumerate() {
+        if i == 0 {
+            program += "G90 G98\n";
+            let (cycle, peck) = match opts.cycle {
+                DrillCycle::G81 => ("G81", String::new()),
+                DrillCycle::G83 { peck } => ("G83", format!(" Q{peck:.4}")),
+            };
+            program += &format!(
+                "{cycle} X{:.4} Y{:.4} Z{:.4} R{:.4}{peck} F{:.1}\n",
+                c.x, c.y, opts.depth, opts.r_plane, opts.feed
+            );
+        } else {
+            program += &format!("X{:.4} Y{:.4}\n", c.x, c.y);
+        }
+    }
+    if !program.is_empty() {
+        program += "G80\n";
+    }
+    program
+}
+
+/// Settings for an A-axis (4th axis) drilling program.
+///
+/// Lengths are in program units and the feed is per minute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolarDrillOpts {
+    /// X position the tool rapids to between holes, clear of the part.
+    pub clearance: f64,
+    /// X position of the bottom of the holes.
+    pub depth: f64,
+    pub feed: f64,
+}
+
+/// Emits a G-code program that drills a pattern by indexing a rotary A axis.
+///
+/// The part is mounted on an A axis that rotates about X, and the spindle is fixed
+/// parallel to X, so each hole is brought under the tool by rotation instead of an XY
+/// move. The pattern is measured about `center`, which must lie on the A axis: the
+/// distance of a point from it becomes the Y offset of the spindle, and its angle the A
+/// position. With the part face in the YZ plane, the hole at `angle` is under the
+/// spindle at `A = angle`; reverse the sign of the angles for a rotary table that turns
+/// the other way.
+///
+/// Each hole is an index move (`G0 Y A`), a feed plunge along X to `opts.depth` and a
+/// rapid retract to `opts.clearance`. The angle is taken from the point's `angle` field
+/// as set by `layout::calc_bolt_circle`, or calculated about `center` when it is `None`.
+///
+/// # Parameters
+/// - coords: Hole positions, in drilling order.
+/// - center: The (x, y) point of the pattern on the A axis, e.g. the bolt-circle center.
+/// - opts: Clearance, depth and feed along X.
+///
+/// # Returns
+/// - `String`: The program, one block per line, or an empty string if there are no
+///   points.
+///
+/// # Example
+/// ```rust
+/// use smithy::gcode::{polar_drill_program, PolarDrillOpts};
+/// use smithy::layout::calc_bolt_circle;
+/// let opts = PolarDrillOpts { clearance: 0.1, depth: -0.5, feed: 5.0 };
+/// let holes = calc_bolt_circle(2.0, 4, None, None, None, None);
+/// let program = polar_drill_program(holes, (0.0, 0.0), opts);
+/// assert!(program.contains("G0 Y1.0000 A90.0000\n"));
+/// ```
+pub fn polar_drill_program(
+    coords: impl Iterator<Item = Coord>,
+    center: (f64, f64),
+    opts: PolarDrillOpts,
+) -> String {
+    let mut program = String::new();
+    for (i, c) in coords.enumerate() {
+        if i == 0 {
+            program += &format!("G90\nG0 X{:.4}\n", opts.clearance);
+        }
+        let (radius, polar_angle) = cart_to_polar(&c, center);
+        let angle = c.angle.unwrap_or(polar_angle.as_degrees());
+        program += &format!("G0 Y{radius:.4} A{angle:.4}\n");
+        program += &format!("G1 X{:.4} F{:.1}\n", opts.depth, opts.feed);
+        program += &format!("G0 X{:.4}\n", opts.clearance);
+    }
+    program
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::calc_bolt_circle;
+    use core::iter;
+
+    #[test]
+    fn test_drill_program() {
+        let holes = [Coord::new(1.234, 5.678), Coord::new(-0.5, 2.0)];
+        let opts = DrillOpts {
+            r_plane: 0.1,
+            depth: -0.625,
+            feed: 4.5,
+            cycle: DrillCycle::G81,
+        };
+        let program = drill_program(holes.clone().into_iter(), opts);
+        assert_eq!(
+            program.lines().collect::<Vec<_>>(),
+            vec![
+                "G90 G98",
+                "G81 X1.2340 Y5.6780 Z-0.6250 R0.1000 F4.5",
+                "X-0.5000 Y2.0000",
+                "G80",
+            ]
+        );
+
+        let opts = DrillOpts {
+            cycle: DrillCycle::G83 { peck: 0.15 },
+            ..opts
+        };
+        let program = drill_program(holes.into_iter(), opts);
+        assert_eq!(
+            program.lines().nth(1),
+            Some("G83 X1.2340 Y5.6780 Z-0.6250 R0.1000 Q0.1500 F4.5")
+        );
+
+        assert_eq!(drill_program(iter::empty(), opts), "");
+    }
+
+    #[test]
+    fn test_polar_drill_program() {
+        let opts = PolarDrillOpts {
+            clearance: 0.1,
+            depth: -0.375,
+            feed: 3.0,
+        };
+        let holes = calc_bolt_circle(3.0, 4, Some(45.0), Some(1.0), Some(-1.0), None);
+        let program = polar_drill_program(holes, (1.0, -1.0), opts);
+        let lines = program.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2 + 4 * 3);
+        assert_eq!(
+            &lines[..5],
+            [
+                "G90",
+                "G0 X0.1000",
+                "G0 Y1.5000 A45.0000",
+                "G1 X-0.3750 F3.0",
+                "G0 X0.1000"
+            ]
+        );
+
+        // The A positions are the bolt-circle angles.
+        let a_values = lines
+            .iter()
+            .filter_map(|l| l.split_once(" A"))
+            .map(|(_, a)| a.parse::<f64>().unwrap())
+            .collect::<Vec<_>>();
+        let angles = calc_bolt_circle(3.0, 4, Some(45.0), Some(1.0), Some(-1.0), None)
+            .map(|c| c.angle.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(a_values, angles);
+        assert_eq!(a_values, vec![45.0, 135.0, 225.0, 315.0]);
+
+        // Points without an angle are measured about the center.
+        let program = polar_drill_program([Coord::new(0.0, -2.0)].into_iter(), (0.0, 0.0), opts);
+        assert!(program.contains("G0 Y2.0000 A270.0000\n"));
+
+        assert_eq!(polar_drill_program(iter::empty(), (0.0, 0.0), opts), "");
+    }
+}
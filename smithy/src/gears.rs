@@ -0,0 +1,357 @@
+use core::f64::consts::PI;
+
+use crate::float;
+
+/// The tooth size of a gear.
+///
+/// - Module: Metric module (m), the pitch diameter in mm per tooth.
+/// - DiametralPitch: Diametral pitch (DP), the number of teeth per inch of pitch
+///   diameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PitchSpec {
+    Module(f64),
+    DiametralPitch(f64),
+}
+
+impl PitchSpec {
+    /// The pitch diameter per tooth, in mm for a module and inches for a diametral pitch.
+    fn dia_per_tooth(&self) -> f64 {
+        match *self {
+            PitchSpec::Module(m) => m,
+            PitchSpec::DiametralPitch(dp) => 1.0 / dp,
+        }
+    }
+}
+
+/// Calculated dimensions of a spur gear. Lengths are in mm for a module gear and in
+/// inches for a diametral pitch gear.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpurGear {
+    teeth: u32,
+    pressure_angle: f64,
+    pitch_dia: f64,
+    outside_dia: f64,
+    root_dia: f64,
+    base_dia: f64,
+    addendum: f64,
+    dedendum: f64,
+    circular_pitch: f64,
+}
+
+impl SpurGear {
+    /// Number of teeth (N).
+    pub fn teeth(&self) -> u32 {
+        self.teeth
+    }
+
+    /// Pressure angle in degrees.
+    pub fn pressure_angle(&self) -> f64 {
+        self.pressure_angle
+    }
+
+    /// Pitch diameter (N × m, or N / DP).
+    pub fn pitch_dia(&self) -> f64 {
+        self.pitch_dia
+    }
+
+    /// Outside diameter, the blank size (pitch diameter + 2 × addendum).
+    pub fn outside_dia(&self) -> f64 {
+        self.outside_dia
+    }
+
+    /// Root diameter (pitch diameter - 2 × dedendum).
+    pub fn root_dia(&self) -> f64 {
+        self.root_dia
+    }
+
+    /// Base circle diameter the involute is generated from (pitch diameter × cos(φ)).
+    pub fn base_dia(&self) -> f64 {
+        self.base_dia
+    }
+
+    /// Addendum, the tooth height above the pitch circle (1 × m, or 1 / DP).
+    pub fn addendum(&self) -> f64 {
+        self.addendum
+    }
+
+    /// Dedendum, the tooth depth below the pitch circle (1.25 × m, or 1.25 / DP).
+    pub fn dedendum(&self) -> f64 {
+        self.dedendum
+    }
+
+    /// Whole depth of the tooth (addendum + dedendum), the depth to cut.
+    pub fn whole_depth(&self) -> f64 {
+        self.addendum + self.dedendum
+    }
+
+    /// Circular pitch, the tooth spacing along the pitch circle (π × m, or π / DP).
+    pub fn circular_pitch(&self) -> f64 {
+        self.circular_pitch
+    }
+}
+
+/// Calculates the basic dimensions of a full-depth involute spur gear.
+///
+/// The proportions are the standard full-depth system, which is the same for module and
+/// diametral pitch gears once the tooth size is expressed as a length per tooth:
+///
+/// ```markdown
+/// pitch dia = N × m        addendum = m        dedendum = 1.25 × m
+/// outside dia = (N + 2) × m                    circular pitch = π × m
+/// ```
+///
+/// where `m` is the module, or `1 / DP` for a diametral pitch gear.
+///
+/// # Parameters
+/// - teeth: Number of teeth (N).
+/// - pitch: The module or diametral pitch.
+/// - pressure_angle: Pressure angle in degrees. Defaults to 20°.
+///
+/// # Returns
+/// - `SpurGear`: The gear dimensions.
+///
+/// # Example
+/// ```rust
+/// use smithy::gears::{spur_gear, PitchSpec};
+/// let gear = spur_gear(20, PitchSpec::Module(2.0), None);
+/// assert_eq!(gear.pitch_dia(), 40.0);
+/// assert_eq!(gear.outside_dia(), 44.0);
+/// ```
+pub fn spur_gear(teeth: u32, pitch: PitchSpec, pressure_angle: Option<f64>) -> SpurGear {
+    let m = pitch.dia_per_tooth();
+    let pressure_angle = pressure_angle.unwrap_or(20.0);
+    let pitch_dia = teeth as f64 * m;
+    let addendum = m;
+    let dedendum = 1.25 * m;
+    SpurGear {
+        teeth,
+        pressure_angle,
+        pitch_dia,
+        outside_dia: pitch_dia + 2.0 * addendum,
+        root_dia: pitch_dia - 2.0 * dedendum,
+        base_dia: pitch_dia * float::cos(pressure_angle.to_radians()),
+        addendum,
+        dedendum,
+        circular_pitch: PI * m,
+    }
+}
+
+/// A lathe change gear train found by `find_change_gears`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GearTrain {
+    drivers: Vec<u32>,
+    driven: Vec<u32>,
+    tpi: f64,
+    error: f64,
+}
+
+#[cfg(feature = "std")]
+impl GearTrain {
+    /// Tooth counts of the driving gears, from the spindle side. A simple train has one
+    /// driver; a compound train pairs each driver after the first with the previous
+    /// driven gear on a common stud.
+    pub fn drivers(&self) -> &[u32] {
+        &self.drivers
+    }
+
+    /// Tooth counts of the driven gears, ending with the gear on the leadscrew.
+    pub fn driven(&self) -> &[u32] {
+        &self.driven
+    }
+
+    /// Threads per inch the train actually cuts.
+    pub fn tpi(&self) -> f64 {
+        self.tpi
+    }
+
+    /// Relative error of the cut pitch against the target, e.g. 0.001 for 0.1%.
+    /// Positive when the train cuts too many threads per inch.
+    pub fn error(&self) -> f64 {
+        self.error
+    }
+}
+
+/// Searches the available change gears for trains that cut a target thread.
+///
+/// The leadscrew turns `drivers / driven` times per spindle revolution, so a train cuts:
+///
+/// ```markdown
+/// TPI = leadscrew TPI × (product of driven) / (product of drivers)
+/// ```
+///
+/// Simple trains (one driver, one driven gear; idlers do not change the ratio) and
+/// compound trains of up to `max_gears` gears are tried, and those whose pitch is within
+/// `tolerance` of the target are kept. Each gear in `available` is
+/// used at most once, so list a size twice if the set has two of them. Only the first
+/// train found for each ratio is kept, which is the one with the fewest gears.
+///
+/// Metric pitches on an inch leadscrew need a factor of 127 for an exact ratio; without
+/// a 127-tooth gear the closest approximation from the set sorts first.
+///
+/// # Parameters
+/// - leadscrew_tpi: Threads per inch of the leadscrew.
+/// - target_tpi: Threads per inch to cut. For a metric pitch use `25.4 / pitch_mm`.
+/// - available: Tooth counts of the change gears on hand.
+/// - max_gears: Largest number of gears in a train, not counting idlers: 2 for simple
+///   trains only, 4 for compound trains with one stud, and so on.
+/// - tolerance: Largest relative error to accept, e.g. 0.001 for 0.1%; 0 keeps exact
+///   trains only.
+///
+/// # Returns
+/// - `Vec<GearTrain>`: Every distinct ratio within the tolerance, sorted by absolute
+///   error and then by the number of gears. Empty if no train is close enough.
+///
+/// # Example
+/// ```rust
+/// use smithy::gears::find_change_gears;
+/// let trains = find_change_gears(8.0, 11.5, &[24, 32, 40, 46, 48, 56, 64], 2, 0.01);
+/// assert_eq!((trains[0].drivers(), trains[0].driven()), (&[32][..], &[46][..]));
+/// ```
+#[cfg(feature = "std")]
+pub fn find_change_gears(
+    leadscrew_tpi: f64,
+    target_tpi: f64,
+    available: &[u32],
+    max_gears: usize,
+    tolerance: f64,
+) -> Vec<GearTrain> {
+    use std::collections::HashSet;
+
+    /// Calls `f` with every `k`-element combination of the indices in `pool`.
+    fn combinations(pool: &[usize], k: usize, cur: &mut Vec<usize>, f: &mut dyn FnMut(&[usize])) {
+        if cur.len() == k {
+            f(cur);
+            return;
+        }
+        for (i, &idx) in pool.iter().enumerate() {
+            cur.push(idx);
+            combinations(&pool[i + 1..], k, cur, f);
+            cur.pop();
+        }
+    }
+
+    fn gcd(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    let mut trains = Vec::new();
+    let mut ratios = HashSet::new();
+    let all: Vec<usize> = (0..available.len()).collect();
+    for k in 1..=max_gears / 2 {
+        combinations(&all, k, &mut Vec::new(), &mut |drivers| {
+            let rest: Vec<usize> = all
+                .iter()
+                .copied()
+                .filter(|i| !drivers.contains(i))
+                .collect();
+            combinations(&rest, k, &mut Vec::new(), &mut |driven| {
+                let num: u64 = drivers.iter().map(|&i| available[i] as u64).product();
+                let den: u64 = driven.iter().map(|&i| available[i] as u64).product();
+                let g = gcd(num, den);
+                if num == 0 || den == 0 || !ratios.insert((num / g, den / g)) {
+                    return;
+                }
+                let tpi = leadscrew_tpi * den as f64 / num as f64;
+                let error = (tpi - target_tpi) / target_tpi;
+                // Allow for float error so an exact train passes a tolerance of zero.
+                if error.abs() > tolerance + 1e-12 {
+                    return;
+                }
+                trains.push(GearTrain {
+                    drivers: drivers.iter().map(|&i| available[i]).collect(),
+                    driven: driven.iter().map(|&i| available[i]).collect(),
+                    tpi,
+                    error,
+                });
+            });
+        });
+    }
+    trains.sort_by(|a, b| {
+        a.error
+            .abs()
+            .total_cmp(&b.error.abs())
+            .then(a.drivers.len().cmp(&b.drivers.len()))
+    });
+    trains
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math;
+
+    #[test]
+    fn test_spur_gear_module() {
+        let gear = spur_gear(20, PitchSpec::Module(2.0), None);
+        assert_eq!(gear.pitch_dia(), 40.0);
+        assert_eq!(gear.outside_dia(), 44.0);
+        assert_eq!(gear.root_dia(), 35.0);
+        assert_eq!(gear.addendum(), 2.0);
+        assert_eq!(gear.dedendum(), 2.5);
+        assert_eq!(gear.whole_depth(), 4.5);
+        assert_eq!(gear.circular_pitch(), 2.0 * PI);
+        assert_eq!(math::round(gear.base_dia(), 4), 37.5877);
+        assert_eq!(gear.pressure_angle(), 20.0);
+
+        let gear = spur_gear(20, PitchSpec::Module(2.0), Some(14.5));
+        assert_eq!(math::round(gear.base_dia(), 4), 38.7259);
+    }
+
+    #[test]
+    fn test_spur_gear_diametral_pitch() {
+        let gear = spur_gear(40, PitchSpec::DiametralPitch(16.0), None);
+        assert_eq!(gear.pitch_dia(), 2.5);
+        assert_eq!(gear.outside_dia(), 2.625);
+        assert_eq!(gear.root_dia(), 2.34375);
+        assert_eq!(gear.addendum(), 0.0625);
+        assert_eq!(gear.dedendum(), 0.078125);
+        assert_eq!(gear.whole_depth(), 0.140625);
+        assert_eq!(math::round(gear.circular_pitch(), 4), 0.1963);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_find_change_gears() {
+        // 11.5 TPI on an 8 TPI leadscrew needs 8 / 11.5 = 32 / 46.
+        let set = [20, 24, 32, 40, 46, 48, 56, 64];
+        let trains = find_change_gears(8.0, 11.5, &set, 4, 0.05);
+        assert_eq!(trains[0].drivers(), &[32]);
+        assert_eq!(trains[0].driven(), &[46]);
+        assert_eq!(trains[0].tpi(), 11.5);
+        assert_eq!(trains[0].error(), 0.0);
+        assert!(trains
+            .windows(2)
+            .all(|w| w[0].error().abs() <= w[1].error().abs()));
+        assert!(trains.iter().all(|t| t.error().abs() <= 0.05));
+        assert!(trains.len() < find_change_gears(8.0, 11.5, &set, 4, 1.0).len());
+
+        // Only the exact train passes a zero tolerance.
+        let exact = find_change_gears(8.0, 11.5, &set, 4, 0.0);
+        assert_eq!(exact.len(), 1);
+        assert_eq!(exact[0], trains[0]);
+
+        // 1.5 mm on an 8 TPI leadscrew is exact with a 127-tooth gear.
+        let target = 25.4 / 1.5;
+        let trains = find_change_gears(8.0, target, &[20, 30, 40, 60, 100, 127], 2, 0.0);
+        assert_eq!(
+            (trains[0].drivers(), trains[0].driven()),
+            (&[60][..], &[127][..])
+        );
+        assert!(trains[0].error().abs() < 1e-12);
+
+        // Without one, a compound train gets within 0.1%.
+        let set = [
+            20, 24, 30, 35, 40, 45, 50, 55, 60, 65, 70, 75, 80, 90, 100, 110, 120,
+        ];
+        assert!(find_change_gears(8.0, target, &set, 2, 0.001).is_empty());
+        let trains = find_change_gears(8.0, target, &set, 4, 0.001);
+        assert_eq!(trains[0].drivers().len(), 2);
+        assert_eq!(math::round(trains[0].tpi(), 4), 16.9231);
+        assert!(trains[0].error().abs() < 0.001);
+    }
+}
@@ -0,0 +1,816 @@
+use crate::float;
+use crate::layout::Coord;
+
+/// A 2D affine transform, stored as the 2×3 matrix `[a b tx; c d ty]`.
+///
+/// A point maps to `x' = a·x + b·y + tx`, `y' = c·x + d·y + ty`. Build one from the
+/// `rotate`, `translate` and `scale` constructors and chain them with `compose` to
+/// position a whole pattern in one step.
+///
+/// When a transform is applied to a `Coord`, `z` passes through unchanged and `angle`
+/// (a direction in degrees) is carried through the rotation/scale part of the matrix:
+/// rotations add to it, mirroring reflects it and translation leaves it alone. The
+/// transformed angle is normalized to `0..360`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    tx: f64,
+    ty: f64,
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Transform2D::identity()
+    }
+}
+
+impl Transform2D {
+    /// The transform that leaves every point where it is.
+    pub fn identity() -> Self {
+        Transform2D::scale(1.0, 1.0)
+    }
+
+    /// A counter-clockwise rotation about the origin by `angle_deg` degrees.
+    pub fn rotate(angle_deg: f64) -> Self {
+        let ang = angle_deg.to_radians();
+        let (sin, cos) = (float::sin(ang), float::cos(ang));
+        Transform2D {
+            a: cos,
+            b: -sin,
+            c: sin,
+            d: cos,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    /// A translation by `(dx, dy)`.
+    pub fn translate(dx: f64, dy: f64) -> Self {
+        Transform2D {
+            tx: dx,
+            ty: dy,
+            ..Transform2D::identity()
+        }
+    }
+
+    /// A scale about the origin. A negative factor mirrors across the other axis.
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Transform2D {
+            a: sx,
+            b: 0.0,
+            c: 0.0,
+            d: sy,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    /// A reflection across the line `y = axis_pos` (`MirrorAxis::X`) or `x = axis_pos`
+    /// (`MirrorAxis::Y`).
+    pub fn mirror(axis: MirrorAxis, axis_pos: f64) -> Self {
+        match axis {
+            MirrorAxis::X => {
+                Transform2D::scale(1.0, -1.0).compose(Transform2D::translate(0.0, 2.0 * axis_pos))
+            }
+            MirrorAxis::Y => {
+                Transform2D::scale(-1.0, 1.0).compose(Transform2D::translate(2.0 * axis_pos, 0.0))
+            }
+        }
+    }
+
+    /// Returns the transform that applies `self` first and then `next`.
+    pub fn compose(self, next: Transform2D) -> Self {
+        Transform2D {
+            a: next.a * self.a + next.b * self.c,
+            b: next.a * self.b + next.b * self.d,
+            c: next.c * self.a + next.d * self.c,
+            d: next.c * self.b + next.d * self.d,
+            tx: next.a * self.tx + next.b * self.ty + next.tx,
+            ty: next.c * self.tx + next.d * self.ty + next.ty,
+        }
+    }
+
+    /// Applies the transform to a single point.
+    pub fn apply(&self, c: &Coord) -> Coord {
+        let angle = c.angle.map(|ang| {
+            let (sin, cos) = (float::sin(ang.to_radians()), float::cos(ang.to_radians()));
+            let dir = float::atan2(self.c * cos + self.d * sin, self.a * cos + self.b * sin);
+            float::rem_euclid(dir.to_degrees(), 360.0)
+        });
+        Coord {
+            x: self.a * c.x + self.b * c.y + self.tx,
+            y: self.c * c.x + self.d * c.y + self.ty,
+            z: c.z,
+            angle,
+        }
+    }
+
+    /// Applies the transform to every point of a pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use smithy::geometry::Transform2D;
+    /// use smithy::layout::calc_bolt_circle;
+    /// let t = Transform2D::rotate(45.0).compose(Transform2D::translate(10.0, 5.0));
+    /// let holes: Vec<_> = t.apply_all(calc_bolt_circle(2.0, 4, None, None, None, None)).collect();
+    /// assert_eq!(holes.len(), 4);
+    /// ```
+    pub fn apply_all(self, coords: impl Iterator<Item = Coord>) -> impl Iterator<Item = Coord> {
+        coords.map(move |c| self.apply(&c))
+    }
+}
+
+/// Z component of the cross product of `o→a` and `o→b`; positive for a left turn.
+#[cfg(feature = "std")]
+fn cross(o: &Coord, a: &Coord, b: &Coord) -> f64 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+/// Calculates the convex hull of a set of points.
+///
+/// Uses Andrew's monotone-chain algorithm. Only the corner points of the hull are
+/// returned: points lying on a hull edge and duplicate points are dropped. The result
+/// starts at the point with the lowest x (then lowest y) and runs counter-clockwise.
+///
+/// # Parameters
+///
+/// - `points`: The points to enclose. Only x and y are considered.
+///
+/// # Returns
+///
+/// Returns the hull vertices in counter-clockwise order. Fewer than three points are
+/// returned when the input has fewer than three distinct points or they are collinear
+/// (the two end points of the line).
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::geometry::convex_hull;
+/// use smithy::layout::calc_bolt_circle;
+/// let holes: Vec<_> = calc_bolt_circle(4.0, 6, None, None, None, None).collect();
+/// assert_eq!(convex_hull(&holes).len(), 6);
+/// ```
+#[cfg(feature = "std")]
+pub fn convex_hull(points: &[Coord]) -> Vec<Coord> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup_by(|a, b| a.x == b.x && a.y == b.y);
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut hull: Vec<Coord> = Vec::with_capacity(sorted.len() + 1);
+    // Lower hull, left to right.
+    for p in &sorted {
+        while hull.len() >= 2 && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p.clone());
+    }
+    // Upper hull, right to left, never popping into the lower hull.
+    let lower_len = hull.len() + 1;
+    for p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], p) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(p.clone());
+    }
+    // The upper hull ends back at the first point.
+    hull.pop();
+    hull
+}
+
+/// Points on a counter-clockwise quarter arc of `radius` about `center`, from `start_deg`.
+#[cfg(feature = "std")]
+fn quarter_arc(
+    center: (f64, f64),
+    radius: f64,
+    start_deg: f64,
+    segments: u32,
+    z: Option<f64>,
+) -> Vec<Coord> {
+    let segments = segments.max(1);
+    (0..=segments)
+        .map(|i| {
+            let ang = (start_deg + 90.0 * i as f64 / segments as f64).to_radians();
+            Coord {
+                x: center.0 + radius * float::cos(ang),
+                y: center.1 + radius * float::sin(ang),
+                z,
+                angle: None,
+            }
+        })
+        .collect()
+}
+
+/// Generates a tangential quarter-arc lead-in that blends into a contour.
+///
+/// The arc turns counter-clockwise (to the left) and ends exactly at `entry`, where its
+/// tangent matches the direction the contour starts in. Entering on a tangent arc
+/// avoids the witness mark a straight plunge onto the profile leaves.
+///
+/// # Parameters
+///
+/// - `entry`: The first point of the contour. Its z is carried onto every arc point.
+/// - `path_start_dir_deg`: The direction of the contour's first move, in degrees.
+/// - `radius`: The radius of the lead-in arc.
+/// - `segments`: The number of straight segments used to approximate the arc (at least 1).
+///
+/// # Returns
+///
+/// Returns `segments + 1` points from the start of the arc to `entry`.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::geometry::lead_in_arc;
+/// use smithy::layout::Coord;
+/// let entry = Coord { x: 0.0, y: 0.0, z: None, angle: None };
+/// let arc = lead_in_arc(entry.clone(), 0.0, 0.25, 8);
+/// assert_eq!(arc.last(), Some(&entry));
+/// ```
+#[cfg(feature = "std")]
+pub fn lead_in_arc(
+    entry: Coord,
+    path_start_dir_deg: f64,
+    radius: f64,
+    segments: u32,
+) -> Vec<Coord> {
+    // The center lies to the left of the start direction.
+    let normal = (path_start_dir_deg + 90.0).to_radians();
+    let center = (
+        entry.x + radius * float::cos(normal),
+        entry.y + radius * float::sin(normal),
+    );
+    let mut arc = quarter_arc(
+        center,
+        radius,
+        path_start_dir_deg - 180.0,
+        segments,
+        entry.z,
+    );
+    // Land exactly on the contour rather than a float-error away from it.
+    *arc.last_mut().unwrap() = entry;
+    arc
+}
+
+/// Generates a tangential quarter-arc lead-out that leaves a contour.
+///
+/// The mirror of `lead_in_arc`: the arc starts exactly at `exit`, tangent to the
+/// direction the contour ends in, and turns counter-clockwise (to the left) away from it.
+///
+/// # Parameters
+///
+/// - `exit`: The last point of the contour. Its z is carried onto every arc point.
+/// - `path_end_dir_deg`: The direction of the contour's last move, in degrees.
+/// - `radius`: The radius of the lead-out arc.
+/// - `segments`: The number of straight segments used to approximate the arc (at least 1).
+///
+/// # Returns
+///
+/// Returns `segments + 1` points from `exit` to the end of the arc.
+#[cfg(feature = "std")]
+pub fn lead_out_arc(exit: Coord, path_end_dir_deg: f64, radius: f64, segments: u32) -> Vec<Coord> {
+    let normal = (path_end_dir_deg + 90.0).to_radians();
+    let center = (
+        exit.x + radius * float::cos(normal),
+        exit.y + radius * float::sin(normal),
+    );
+    let mut arc = quarter_arc(center, radius, path_end_dir_deg - 90.0, segments, exit.z);
+    arc[0] = exit;
+    arc
+}
+
+/// Unit normal to the left of the segment `a→b`.
+#[cfg(feature = "std")]
+fn left_normal(a: &Coord, b: &Coord) -> (f64, f64) {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len = float::sqrt(dx * dx + dy * dy);
+    (-dy / len, dx / len)
+}
+
+/// Offsets a path sideways by a constant distance, e.g. the cutter or wire radius.
+///
+/// Each segment is moved along its normal, and each vertex along the average of the
+/// normals of the segments meeting there, scaled so the offset segments still meet:
+///
+/// ```markdown
+/// v = offset × (n1 + n2) / (1 + n1 · n2)
+/// ```
+///
+/// This keeps sharp corners sharp (a square grows into a larger square) rather than
+/// rounding them. Collinear points just move along the shared normal. A path that turns
+/// straight back on itself is offset along the incoming segment's normal at the turn.
+///
+/// # Parameters
+///
+/// - `coords`: The path. Repeated consecutive points are merged. For a closed path the
+///   last point may repeat the first.
+/// - `offset`: The distance to offset, to the left of the direction of travel when
+///   positive and to the right when negative. A counter-clockwise contour grows with a
+///   negative offset.
+/// - `closed`: Whether the last point joins back to the first. Open paths are offset
+///   square to the first and last segments at their ends.
+///
+/// # Returns
+///
+/// Returns the offset path with one point per vertex, keeping each point's `z` and
+/// `angle`. Paths with fewer than two distinct points are returned unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::geometry::offset_path;
+/// use smithy::layout::Coord;
+/// let line = [Coord::new(0.0, 0.0), Coord::new(2.0, 0.0)];
+/// let offset = offset_path(&line, 0.5, false);
+/// assert_eq!((offset[1].x, offset[1].y), (2.0, 0.5));
+/// ```
+#[cfg(feature = "std")]
+pub fn offset_path(coords: &[Coord], offset: f64, closed: bool) -> Vec<Coord> {
+    let mut pts = coords.to_vec();
+    pts.dedup_by(|a, b| a.x == b.x && a.y == b.y);
+    let repeat_end = closed
+        && pts.len() > 2
+        && pts[0].x == pts[pts.len() - 1].x
+        && pts[0].y == pts[pts.len() - 1].y;
+    if repeat_end {
+        pts.pop();
+    }
+    let n = pts.len();
+    if n < 2 {
+        return pts;
+    }
+
+    // Normal of the segment from vertex i to the next one.
+    let normal = |i: usize| left_normal(&pts[i], &pts[(i + 1) % n]);
+    let mut result = (0..n)
+        .map(|i| {
+            let prev = if i > 0 {
+                Some(normal(i - 1))
+            } else if closed {
+                Some(normal(n - 1))
+            } else {
+                None
+            };
+            let next = (closed || i < n - 1).then(|| normal(i));
+            let (vx, vy) = match (prev, next) {
+                (Some(a), Some(b)) => {
+                    let k = 1.0 + a.0 * b.0 + a.1 * b.1;
+                    if k < 1e-9 {
+                        a
+                    } else {
+                        ((a.0 + b.0) / k, (a.1 + b.1) / k)
+                    }
+                }
+                (Some(a), None) | (None, Some(a)) => a,
+                (None, None) => unreachable!(),
+            };
+            Coord {
+                x: pts[i].x + offset * vx,
+                y: pts[i].y + offset * vy,
+                ..pts[i].clone()
+            }
+        })
+        .collect::<Vec<_>>();
+    if repeat_end {
+        result.push(result[0].clone());
+    }
+    result
+}
+
+/// Finds the circle passing through three points.
+///
+/// Useful for recovering a bolt circle from three measured holes. The center is the
+/// circumcenter of the triangle formed by the points.
+///
+/// # Parameters
+///
+/// - `p1`, `p2`, `p3`: Three points on the circle. Only x and y are considered.
+///
+/// # Returns
+///
+/// Returns the center and diameter of the circle, or `None` if the points are collinear
+/// (or coincident) and no circle passes through them.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::geometry::fit_circle;
+/// use smithy::layout::Coord;
+/// let p = |x, y| Coord { x, y, z: None, angle: None };
+/// let (center, dia) = fit_circle(&p(2.0, 0.0), &p(0.0, 2.0), &p(-2.0, 0.0)).unwrap();
+/// assert_eq!((center.x, center.y, dia), (0.0, 0.0, 4.0));
+/// ```
+pub fn fit_circle(p1: &Coord, p2: &Coord, p3: &Coord) -> Option<(Coord, f64)> {
+    let (bx, by) = (p2.x - p1.x, p2.y - p1.y);
+    let (cx, cy) = (p3.x - p1.x, p3.y - p1.y);
+    let b2 = bx * bx + by * by;
+    let c2 = cx * cx + cy * cy;
+    let d = 2.0 * (bx * cy - by * cx);
+    // Relative test so the collinearity check doesn't depend on the units or the size of
+    // the pattern.
+    if d.abs() <= 1e-12 * float::sqrt(b2 * c2) || b2 == 0.0 || c2 == 0.0 {
+        return None;
+    }
+    let ux = (cy * b2 - by * c2) / d;
+    let uy = (bx * c2 - cx * b2) / d;
+    let center = Coord {
+        x: p1.x + ux,
+        y: p1.y + uy,
+        z: None,
+        angle: None,
+    };
+    Some((center, 2.0 * float::sqrt(ux * ux + uy * uy)))
+}
+
+/// Fits a circle to many measured points by least squares.
+///
+/// Uses the Kåsa method, which minimizes the algebraic distance
+/// `(x - a)² + (y - b)² - r²` and reduces to a 2×2 linear system. The points are
+/// centered on their mean first to keep the system well conditioned. The fit is
+/// unbiased for points spread around the whole circle; for short arcs it tends to
+/// underestimate the radius.
+///
+/// # Parameters
+///
+/// - `points`: The measured points. Only x and y are considered.
+///
+/// # Returns
+///
+/// Returns the fitted center and radius, or `None` if there are fewer than three points
+/// or they are collinear.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::geometry::fit_circle_lsq;
+/// use smithy::layout::calc_bolt_circle;
+/// let holes: Vec<_> = calc_bolt_circle(4.0, 8, None, Some(1.0), None, None).collect();
+/// let (center, radius) = fit_circle_lsq(&holes).unwrap();
+/// assert!((center.x - 1.0).abs() < 1e-9 && (radius - 2.0).abs() < 1e-9);
+/// ```
+pub fn fit_circle_lsq(points: &[Coord]) -> Option<(Coord, f64)> {
+    if points.len() < 3 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mx = points.iter().map(|c| c.x).sum::<f64>() / n;
+    let my = points.iter().map(|c| c.y).sum::<f64>() / n;
+    let (mut suu, mut svv, mut suv) = (0.0, 0.0, 0.0);
+    let (mut suuu, mut svvv, mut suvv, mut svuu) = (0.0, 0.0, 0.0, 0.0);
+    for c in points {
+        let (u, v) = (c.x - mx, c.y - my);
+        suu += u * u;
+        svv += v * v;
+        suv += u * v;
+        suuu += u * u * u;
+        svvv += v * v * v;
+        suvv += u * v * v;
+        svuu += v * u * u;
+    }
+    let det = suu * svv - suv * suv;
+    // Collinear points make the system singular; compare against the spread of the
+    // points so the test doesn't depend on units.
+    if det.abs() <= 1e-12 * (suu + svv) * (suu + svv) {
+        return None;
+    }
+    let ru = (suuu + suvv) / 2.0;
+    let rv = (svvv + svuu) / 2.0;
+    let a = (ru * svv - rv * suv) / det;
+    let b = (rv * suu - ru * suv) / det;
+    let center = Coord {
+        x: mx + a,
+        y: my + b,
+        z: None,
+        angle: None,
+    };
+    Some((center, float::sqrt(a * a + b * b + (suu + svv) / n)))
+}
+
+/// The line a pattern is mirrored across by `Transform2D::mirror`, `mirror_array` and
+/// `layout::mirror_coords`. The variant names the axis the line runs along, so mirroring
+/// across the Y axis negates x.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MirrorAxis {
+    /// The horizontal line `y = axis_pos`, along the X axis; y values are reflected.
+    X,
+    /// The vertical line `x = axis_pos`, along the Y axis; x values are reflected.
+    Y,
+}
+
+/// Mirrors a half pattern across an axis to build the full symmetric pattern.
+///
+/// The original points are returned first, followed by their mirror images in the same
+/// order. Points within `dedup_tol` of the mirror line are not mirrored, so a hole on the
+/// centerline is only drilled once. Angles are reflected along with the positions.
+///
+/// # Parameters
+///
+/// - `points`: The half pattern.
+/// - `axis`: Which kind of line to mirror across.
+/// - `axis_pos`: The position of the mirror line along the reflected axis.
+/// - `dedup_tol`: The distance from the mirror line within which a point is treated as
+///   lying on it.
+///
+/// # Returns
+///
+/// Returns the original points followed by the mirrored off-axis points.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::geometry::{mirror_array, MirrorAxis};
+/// use smithy::layout::Coord;
+/// let half = [
+///     Coord { x: 1.0, y: 0.0, z: None, angle: None },
+///     Coord { x: 3.0, y: 0.0, z: None, angle: None },
+/// ];
+/// let full = mirror_array(&half, MirrorAxis::Y, 1.0, 0.0001);
+/// assert_eq!(full.len(), 3);
+/// assert_eq!(full[2].x, -1.0);
+/// ```
+#[cfg(feature = "std")]
+pub fn mirror_array(
+    points: &[Coord],
+    axis: MirrorAxis,
+    axis_pos: f64,
+    dedup_tol: f64,
+) -> Vec<Coord> {
+    let mirror = Transform2D::mirror(axis, axis_pos);
+    let dist = |c: &Coord| match axis {
+        MirrorAxis::X => (c.y - axis_pos).abs(),
+        MirrorAxis::Y => (c.x - axis_pos).abs(),
+    };
+    let mirrored = points
+        .iter()
+        .filter(|c| dist(c) > dedup_tol)
+        .map(|c| mirror.apply(c));
+    points.iter().cloned().chain(mirrored).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::layout::calc_alt_grid;
+    use crate::layout::calc_bolt_circle;
+    use crate::math;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_convex_hull() {
+        let grid = calc_alt_grid(0.0, 4, 1.0, 0.0, 3, 1.0).collect::<Vec<_>>();
+        let actual = convex_hull(&grid)
+            .iter()
+            .map(|c| (c.x, c.y))
+            .collect::<Vec<_>>();
+        assert_eq!(actual, vec![(0.0, 0.0), (3.0, 0.0), (3.0, 2.0), (0.0, 2.0)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_convex_hull_degenerate() {
+        let line = calc_alt_grid(0.0, 5, 1.0, 0.0, 1, 1.0).collect::<Vec<_>>();
+        let actual = convex_hull(&line)
+            .iter()
+            .map(|c| (c.x, c.y))
+            .collect::<Vec<_>>();
+        assert_eq!(actual, vec![(0.0, 0.0), (4.0, 0.0)]);
+
+        let mut dupes = calc_alt_grid(0.0, 1, 1.0, 0.0, 1, 1.0).collect::<Vec<_>>();
+        dupes.extend(calc_alt_grid(0.0, 1, 1.0, 0.0, 1, 1.0));
+        assert_eq!(convex_hull(&dupes).len(), 1);
+        assert!(convex_hull(&[]).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    /// Direction of travel from `a` to `b` in degrees.
+    fn heading(a: &Coord, b: &Coord) -> f64 {
+        (b.y - a.y).atan2(b.x - a.x).to_degrees()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_lead_in_arc() {
+        let entry = Coord {
+            x: 2.0,
+            y: 1.0,
+            z: Some(-0.1),
+            angle: None,
+        };
+        let arc = lead_in_arc(entry.clone(), 30.0, 0.5, 90);
+        assert_eq!(arc.len(), 91);
+        assert_eq!(arc[90], entry);
+        assert!(arc.iter().all(|c| c.z == Some(-0.1)));
+        // The last chord lags the tangent by half a segment (0.5°).
+        assert_eq!(math::round(heading(&arc[89], &arc[90]), 9), 29.5);
+        // The arc starts a quarter turn back, heading 90° to the right of the contour.
+        assert_eq!(math::round(heading(&arc[0], &arc[1]), 9), -59.5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_lead_out_arc() {
+        let exit = Coord {
+            x: 0.0,
+            y: 0.0,
+            z: None,
+            angle: None,
+        };
+        let arc = lead_out_arc(exit.clone(), 0.0, 1.0, 4);
+        assert_eq!(arc[0], exit);
+        assert_eq!(math::round(arc[4].x, 9), 1.0);
+        assert_eq!(math::round(arc[4].y, 9), 1.0);
+        assert_eq!(math::round(heading(&arc[0], &arc[1]), 9), 11.25);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_offset_path() {
+        let xy = |path: Vec<Coord>| {
+            path.iter()
+                .map(|c| (math::round(c.x, 9), math::round(c.y, 9)))
+                .collect::<Vec<_>>()
+        };
+        // A counter-clockwise 2 × 2 square grows by 0.25 all round.
+        let square = [
+            Coord::new(0.0, 0.0),
+            Coord::new(2.0, 0.0),
+            Coord::new(2.0, 2.0),
+            Coord::new(0.0, 2.0),
+        ];
+        assert_eq!(
+            xy(offset_path(&square, -0.25, true)),
+            vec![(-0.25, -0.25), (2.25, -0.25), (2.25, 2.25), (-0.25, 2.25)]
+        );
+        assert_eq!(
+            xy(offset_path(&square, 0.25, true)),
+            vec![(0.25, 0.25), (1.75, 0.25), (1.75, 1.75), (0.25, 1.75)]
+        );
+
+        // A repeated closing point is kept, and matches the first.
+        let mut looped = square.to_vec();
+        looped.push(square[0].clone());
+        let offset = offset_path(&looped, -0.25, true);
+        assert_eq!(offset.len(), 5);
+        assert_eq!(offset[4], offset[0]);
+
+        // Open ends are offset square to their segment; collinear and repeated points
+        // just move along the normal.
+        let open = [
+            Coord::new(0.0, 0.0),
+            Coord::new(1.0, 0.0),
+            Coord::new(1.0, 0.0),
+            Coord::new(2.0, 0.0),
+            Coord::new(2.0, 1.0),
+        ];
+        assert_eq!(
+            xy(offset_path(&open, 0.1, false)),
+            vec![(0.0, 0.1), (1.0, 0.1), (1.9, 0.1), (1.9, 1.0)]
+        );
+
+        // Turning straight back offsets along the incoming normal.
+        let back = [
+            Coord::new(0.0, 0.0),
+            Coord::new(1.0, 0.0),
+            Coord::new(0.0, 0.0),
+        ];
+        assert_eq!(xy(offset_path(&back, 0.1, false))[1], (1.0, 0.1));
+
+        assert_eq!(
+            offset_path(&square[..1], 0.1, true),
+            vec![square[0].clone()]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_mirror_array() {
+        let half = [
+            Coord {
+                x: 0.5,
+                y: 1.0,
+                z: None,
+                angle: Some(0.0),
+            },
+            Coord {
+                x: 2.0,
+                y: 1.00005,
+                z: None,
+                angle: None,
+            },
+            Coord {
+                x: 3.0,
+                y: 2.5,
+                z: Some(-0.5),
+                angle: None,
+            },
+        ];
+        let actual = mirror_array(&half, MirrorAxis::X, 1.0, 0.0001)
+            .iter()
+            .map(|c| (c.x, c.y, c.z))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            vec![
+                (0.5, 1.0, None),
+                (2.0, 1.00005, None),
+                (3.0, 2.5, Some(-0.5)),
+                (3.0, -0.5, Some(-0.5)),
+            ]
+        );
+
+        let full = mirror_array(&half, MirrorAxis::Y, 0.0, 0.0001);
+        assert_eq!(full.len(), 6);
+        assert_eq!((full[3].x, full[3].y), (-0.5, 1.0));
+        assert_eq!(math::round(full[3].angle.unwrap(), 9), 180.0);
+    }
+
+    #[test]
+    fn test_fit_circle() {
+        let holes = calc_bolt_circle(5.25, 7, Some(10.0), Some(3.0), Some(-1.5), None)
+            .take(3)
+            .collect::<Vec<_>>();
+        let (center, dia) = fit_circle(&holes[0], &holes[1], &holes[2]).unwrap();
+        assert_eq!(math::round(center.x, 9), 3.0);
+        assert_eq!(math::round(center.y, 9), -1.5);
+        assert_eq!(math::round(dia, 9), 5.25);
+
+        let p = |x, y| Coord {
+            x,
+            y,
+            z: None,
+            angle: None,
+        };
+        assert!(fit_circle(&p(0.0, 0.0), &p(1.0, 1.0), &p(3.0, 3.0)).is_none());
+        assert!(fit_circle(&p(1.0, 1.0), &p(1.0, 1.0), &p(3.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn test_fit_circle_lsq() {
+        // A 2.5" radius circle probed at 24 points with up to ±0.0005" of noise.
+        let points = calc_bolt_circle(5.0, 24, Some(7.0), Some(-4.0), Some(6.0), None)
+            .enumerate()
+            .map(|(i, p)| {
+                let noise = 0.0005 * float::sin(i as f64 * 2.7);
+                Coord {
+                    x: p.x + noise,
+                    y: p.y - noise * 0.6,
+                    z: None,
+                    angle: None,
+                }
+            })
+            .collect::<Vec<_>>();
+        let (center, radius) = fit_circle_lsq(&points).unwrap();
+        assert!((center.x + 4.0).abs() < 0.0005);
+        assert!((center.y - 6.0).abs() < 0.0005);
+        assert!((radius - 2.5).abs() < 0.0005);
+
+        assert!(fit_circle_lsq(&points[..2]).is_none());
+        let line = [0.0, 1.0, 2.0, 3.0].map(|x| Coord {
+            x,
+            y: 2.0 * x,
+            z: None,
+            angle: None,
+        });
+        assert!(fit_circle_lsq(&line).is_none());
+    }
+
+    #[test]
+    fn test_transform_compose() {
+        let p = Coord {
+            x: 2.0,
+            y: 1.0,
+            z: Some(-0.25),
+            angle: Some(30.0),
+        };
+        let rotate = Transform2D::rotate(90.0);
+        let translate = Transform2D::translate(5.0, -1.0);
+        let composed = rotate.compose(translate).apply(&p);
+        let sequential = translate.apply(&rotate.apply(&p));
+        assert!(composed.approx_eq(&sequential, 1e-12));
+        assert!(composed.approx_eq(
+            &Coord {
+                x: 4.0,
+                y: 1.0,
+                z: Some(-0.25),
+                angle: Some(120.0),
+            },
+            1e-12
+        ));
+
+        // Mirroring across the Y axis reflects the angle.
+        let mirrored = Transform2D::scale(-1.0, 1.0).apply(&p);
+        assert_eq!(mirrored.x, -2.0);
+        assert_eq!(math::round(mirrored.angle.unwrap(), 9), 150.0);
+
+        assert!(Transform2D::identity().apply(&p).approx_eq(&p, 1e-12));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::layout::Coord;
+
+/// Settings for a Heidenhain drilling program.
+///
+/// Lengths are in the program units (millimeters, or inches when `inch` is set) and
+/// feeds are per minute in the same units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeidenhainOptions {
+    /// Program name used in the `BEGIN PGM` and `END PGM` blocks.
+    pub name: String,
+    /// Emit an `INCH` program instead of `MM`.
+    pub inch: bool,
+    pub tool: u32,
+    pub rpm: f64,
+    /// Set-up clearance above the surface where the plunge starts (Q200).
+    pub clearance: f64,
+    /// Hole depth below the surface, as a positive number (Q201).
+    pub depth: f64,
+    /// Plunging feed rate (Q206).
+    pub plunge_feed: f64,
+    /// Depth of each peck; equal to `depth` for a single plunge (Q202).
+    pub peck: f64,
+    /// Z coordinate of the workpiece surface (Q203).
+    pub surface: f64,
+    /// Second set-up clearance, the height traversed between holes (Q204).
+    pub retract: f64,
+}
+
+impl Default for HeidenhainOptions {
+    fn default() -> Self {
+        HeidenhainOptions {
+            name: "DRILL".to_string(),
+            inch: false,
+            tool: 1,
+            rpm: 1000.0,
+            clearance: 2.0,
+            depth: 10.0,
+            plunge_feed: 100.0,
+            peck: 10.0,
+            surface: 0.0,
+            retract: 50.0,
+        }
+    }
+}
+
+/// Emits a Heidenhain conversational program that drills every point.
+///
+/// The program calls the tool, defines `CYCL DEF 200 DRILLING` from the options and then
+/// positions over each hole with an `L` block ending in `M99`, which runs the cycle
+/// there. Depth comes from the cycle, so the `z` of each point is ignored.
+///
+/// Every block is numbered and every coordinate is signed, as the control expects.
+///
+/// # Parameters
+/// - points: Hole positions, in drilling order.
+/// - opts: Tool, spindle and cycle settings.
+///
+/// # Returns
+/// - `String`: The program, one numbered block per line.
+///
+/// # Example
+/// ```rust
+/// use smithy::heidenhain::{to_heidenhain, HeidenhainOptions};
+/// use smithy::layout::calc_bolt_circle;
+/// let pgm = to_heidenhain(calc_bolt_circle(50.0, 4, None, None, None, None), HeidenhainOptions::default());
+/// assert!(pgm.contains("CYCL DEF 200 DRILLING"));
+/// assert_eq!(pgm.lines().filter(|l| l.ends_with("M99")).count(), 4);
+/// ```
+pub fn to_heidenhain(points: impl Iterator<Item = Coord>, opts: HeidenhainOptions) -> String {
+    let (units, prec) = if opts.inch { ("INCH", 4) } else { ("MM", 3) };
+    let num = |v: f64| format!("{v:+.prec$}");
+
+    let mut blocks = vec![
+        format!("BEGIN PGM {} {units}", opts.name),
+        format!("TOOL CALL {} Z S{:.0}", opts.tool, opts.rpm),
+        format!("L Z{} R0 FMAX M3", num(opts.retract)),
+        "CYCL DEF 200 DRILLING ~".to_string(),
+        format!("  Q200={} ;SET-UP CLEARANCE ~", num(opts.clearance)),
+        format!("  Q201={} ;DEPTH ~", num(-opts.depth.abs())),
+        format!("  Q206={} ;FEED RATE FOR PLNGNG ~", num(opts.plunge_feed)),
+        format!("  Q202={} ;PLUNGING DEPTH ~", num(opts.peck)),
+        format!("  Q210={} ;DWELL TIME AT TOP ~", num(0.0)),
+        format!("  Q203={} ;SURFACE COORDINATE ~", num(opts.surface)),
+        format!("  Q204={} ;2ND SET-UP CLEARANCE ~", num(opts.retract)),
+        format!("  Q211={} ;DWELL TIME AT DEPTH", num(0.0)),
+    ];
+    for p in points {
+        blocks.push(format!("L X{} Y{} R0 FMAX M99", num(p.x), num(p.y)));
+    }
+    blocks.push(format!("L Z{} R0 FMAX M30", num(opts.retract)));
+    blocks.push(format!("END PGM {} {units}", opts.name));
+
+    // Only the first line of a block carries a number; `~` continues it.
+    let mut pgm = String::new();
+    let mut n = 0;
+    for block in blocks {
+        if block.starts_with(' ') {
+            pgm += &format!("{block}\n");
+        } else {
+            pgm += &format!("{n} {block}\n");
+            n += 1;
+        }
+    }
+    pgm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::calc_alt_grid;
+
+    #[test]
+    fn test_to_heidenhain() {
+        let holes = calc_alt_grid(10.0, 3, 15.0, 5.0, 2, 20.0);
+        let opts = HeidenhainOptions {
+            name: "PLATE".to_string(),
+            depth: 12.5,
+            ..HeidenhainOptions::default()
+        };
+        let pgm = to_heidenhain(holes, opts);
+        let lines = pgm.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "0 BEGIN PGM PLATE MM");
+        assert_eq!(lines[3], "3 CYCL DEF 200 DRILLING ~");
+        assert!(lines.contains(&"  Q201=-12.500 ;DEPTH ~"));
+        let moves = lines
+            .iter()
+            .filter(|l| l.contains(" L X"))
+            .collect::<Vec<_>>();
+        assert_eq!(moves.len(), 6);
+        assert_eq!(*moves[0], "4 L X+10.000 Y+5.000 R0 FMAX M99");
+        assert_eq!(*lines.last().unwrap(), "11 END PGM PLATE MM");
+    }
+}
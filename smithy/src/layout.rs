@@ -1,10 +1,30 @@
-use std::iter;
+use core::{fmt, iter};
 
-#[derive(Debug)]
+use crate::float;
+use crate::geometry::{MirrorAxis, Transform2D};
+use crate::math::{self, Angle};
+
+/// A point produced by the layout functions.
+///
+/// `angle` is the angular position of the point in degrees (see `math::Angle` for a
+/// unit-safe wrapper), and `z` is the depth when a pattern carries one.
+///
+/// With the `serde` feature, a `z` or `angle` of `None` is left out when serializing and
+/// may be missing or null when deserializing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coord {
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub angle: Option<f64>,
     pub x: f64,
     pub y: f64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub z: Option<f64>,
 }
 
@@ -21,6 +41,10 @@ pub struct Coord {
 /// - `st_angle`: Optional starting angle in degrees (default is 0).
 /// - `xc`: Optional x-coordinate for the center of the circle (default is 0.0).
 /// - `yc`: Optional y-coordinate for the center of the circle (default is 0.0).
+/// - `sweep`: Optional arc in degrees to spread the points over, counter-clockwise from
+///   `st_angle` (negative for clockwise). Points fall on both ends of a partial arc, so
+///   the step is `sweep / (num - 1)`. The default, or any sweep of 360° or more, spreads
+///   the points evenly around the full circle with a step of `360 / num`.
 ///
 /// # Returns
 ///
@@ -38,21 +62,471 @@ pub fn calc_bolt_circle(
     st_angle: Option<f64>,
     xc: Option<f64>,
     yc: Option<f64>,
+    sweep: Option<f64>,
 ) -> impl Iterator<Item = Coord> {
     let st_angle = st_angle.unwrap_or_default();
     let xc = xc.unwrap_or_default();
     let yc = yc.unwrap_or_default();
-    let step = 360.0 / num as f64;
+    let step = match sweep {
+        Some(sweep) if sweep.abs() < 360.0 => {
+            if num > 1 {
+                sweep / (num - 1) as f64
+            } else {
+                0.0
+            }
+        }
+        _ => 360.0 / num as f64,
+    };
     let rd = dia / 2.0;
-    (0..num).map(move |i| {
-        let ang = (st_angle + i as f64 * step).to_radians();
-        let x = xc + rd * ang.cos();
-        let y = yc + rd * ang.sin();
+    (0..num).map(move |i| polar_to_cart(rd, st_angle + i as f64 * step, (xc, yc)))
+}
+
+/// Converts a polar position about a center to a point.
+///
+/// # Parameters
+///
+/// - `radius`: The distance from the center.
+/// - `angle`: The angle counter-clockwise from +X; a bare `f64` is in degrees.
+/// - `center`: The (x, y) center the position is measured from.
+///
+/// # Returns
+///
+/// Returns a `Coord` at the position, with `angle` as given, in degrees, in the `angle`
+/// field.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::polar_to_cart;
+/// let p = polar_to_cart(2.0, 90.0, (1.0, 1.0));
+/// assert!((p.x - 1.0).abs() < 1e-12 && (p.y - 3.0).abs() < 1e-12);
+/// ```
+pub fn polar_to_cart(radius: f64, angle: impl Into<Angle>, center: (f64, f64)) -> Coord {
+    let angle = angle.into();
+    let ang = angle.as_radians();
+    Coord {
+        x: center.0 + radius * float::cos(ang),
+        y: center.1 + radius * float::sin(ang),
+        z: None,
+        angle: Some(angle.as_degrees()),
+    }
+}
+
+/// Converts a point to a polar position about a center.
+///
+/// # Parameters
+///
+/// - `coord`: The point to convert. Its `z` and `angle` are ignored.
+/// - `center`: The (x, y) center to measure from.
+///
+/// # Returns
+///
+/// Returns `(radius, angle)`, with the angle counter-clockwise from +X and within
+/// 0..360 degrees.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{cart_to_polar, Coord};
+/// let (r, ang) = cart_to_polar(&Coord::new(0.0, -2.0), (0.0, 0.0));
+/// assert_eq!((r, ang.as_degrees()), (2.0, 270.0));
+/// ```
+pub fn cart_to_polar(coord: &Coord, center: (f64, f64)) -> (f64, Angle) {
+    let dx = coord.x - center.0;
+    let dy = coord.y - center.1;
+    (
+        float::sqrt(dx * dx + dy * dy),
+        Angle::radians(float::atan2(dy, dx)).normalized(),
+    )
+}
+
+/// Calculates the chord between adjacent holes of an evenly spaced bolt circle.
+///
+/// This is the center distance measured across two neighbouring holes, the usual way to
+/// check a pattern with calipers:
+///
+/// ```markdown
+/// chord = dia × sin(180° / num)
+/// ```
+///
+/// # Parameters
+///
+/// - `dia`: Diameter of the bolt circle.
+/// - `num`: Number of holes around the full circle.
+///
+/// # Returns
+///
+/// Returns the chord length, or 0 for fewer than two holes.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::bolt_circle_chord;
+/// assert!((bolt_circle_chord(2.0, 6) - 1.0).abs() < 1e-12);
+/// ```
+pub fn bolt_circle_chord(dia: f64, num: u32) -> f64 {
+    if num < 2 {
+        return 0.0;
+    }
+    dia * float::sin(core::f64::consts::PI / num as f64)
+}
+
+/// Pairs each point of a pattern with its distance to the next point.
+///
+/// For a closed pattern, such as a bolt circle around the full 360°, the last point is
+/// paired with its distance back to the first, so every hole carries the chord to its
+/// counter-clockwise neighbour. A partial arc is open: its last hole has no neighbour
+/// across the gap and gets `None`.
+///
+/// # Parameters
+///
+/// - `points`: The points of the pattern, in order.
+/// - `closed`: Whether the last point neighbours the first.
+///
+/// # Returns
+///
+/// Returns an iterator that yields each point with the distance to its neighbour, or
+/// `None` for the last point of an open pattern.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_bolt_circle, with_chords, Coord};
+/// let holes: Vec<Coord> = calc_bolt_circle(2.0, 6, None, None, None, None).collect();
+/// assert!(with_chords(&holes, true).all(|(_, chord)| (chord.unwrap() - 1.0).abs() < 1e-12));
+/// ```
+pub fn with_chords(
+    points: &[Coord],
+    closed: bool,
+) -> impl Iterator<Item = (Coord, Option<f64>)> + '_ {
+    points.iter().enumerate().map(move |(i, c)| {
+        let next = if i + 1 < points.len() {
+            Some(&points[i + 1])
+        } else if closed {
+            points.first()
+        } else {
+            None
+        };
+        (c.clone(), next.map(|n| c.distance_to(n)))
+    })
+}
+
+/// A bolt circle pattern, configured with chained setters.
+///
+/// This is a readable alternative to `calc_bolt_circle`, whose optional arguments are
+/// easy to mix up at the call site. Settings that are not given take the same defaults
+/// as the free function.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_bolt_circle, BoltCircle};
+/// let holes: Vec<_> = BoltCircle::new()
+///     .diameter(6.0)
+///     .count(5)
+///     .start_angle(20.0)
+///     .build()
+///     .collect();
+/// let same: Vec<_> = calc_bolt_circle(6.0, 5, Some(20.0), None, None, None).collect();
+/// assert_eq!(holes, same);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BoltCircle {
+    dia: f64,
+    num: u32,
+    start_angle: Option<f64>,
+    center: Option<(f64, f64)>,
+    sweep: Option<f64>,
+}
+
+impl BoltCircle {
+    /// Creates an empty bolt circle with no holes, centered on the origin.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the diameter of the bolt circle.
+    pub fn diameter(mut self, dia: f64) -> Self {
+        self.dia = dia;
+        self
+    }
+
+    /// Sets the number of holes.
+    pub fn count(mut self, num: u32) -> Self {
+        self.num = num;
+        self
+    }
+
+    /// Sets the angle of the first hole (default 0); a bare `f64` is in degrees.
+    pub fn start_angle(mut self, angle: impl Into<Angle>) -> Self {
+        self.start_angle = Some(angle.into().as_degrees());
+        self
+    }
+
+    /// Sets the center of the circle (default the origin).
+    pub fn center(mut self, x: f64, y: f64) -> Self {
+        self.center = Some((x, y));
+        self
+    }
+
+    /// Spreads the holes over a partial arc instead of the full circle (see the `sweep`
+    /// parameter of `calc_bolt_circle`); a bare `f64` is in degrees.
+    pub fn sweep(mut self, sweep: impl Into<Angle>) -> Self {
+        self.sweep = Some(sweep.into().as_degrees());
+        self
+    }
+
+    /// Returns the chord between adjacent holes, allowing for a partial `sweep`.
+    pub fn chord(&self) -> f64 {
+        match self.sweep {
+            Some(sweep) if sweep.abs() < 360.0 => {
+                if self.num < 2 {
+                    return 0.0;
+                }
+                let step = (sweep / (self.num - 1) as f64).to_radians();
+                (self.dia * float::sin(step / 2.0)).abs()
+            }
+            _ => bolt_circle_chord(self.dia, self.num),
+        }
+    }
+
+    /// Returns an iterator over the hole positions.
+    pub fn build(&self) -> impl Iterator<Item = Coord> {
+        calc_bolt_circle(
+            self.dia,
+            self.num,
+            self.start_angle,
+            self.center.map(|c| c.0),
+            self.center.map(|c| c.1),
+            self.sweep,
+        )
+    }
+}
+
+/// Reconstructs a bolt circle from two adjacent holes and the hole count.
+///
+/// The two holes subtend `360° / num` at the center, so the chord between them gives the
+/// diameter:
+///
+/// ```markdown
+/// D = chord / sin(180° / num)
+/// ```
+///
+/// `h2` is taken to be the next hole counter-clockwise from `h1`, which puts the center
+/// to the left of the chord from `h1` to `h2`.
+///
+/// # Parameters
+///
+/// - `h1`: A known hole position.
+/// - `h2`: The known hole adjacent to `h1`, counter-clockwise around the circle.
+/// - `num`: Total number of holes in the circle.
+///
+/// # Returns
+///
+/// Returns every hole of the circle starting at `h1`, or `None` if `num` is less than 2
+/// or the holes coincide.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{bolt_circle_from_two, Coord};
+/// let holes = bolt_circle_from_two(&Coord::new(1.0, 0.0), &Coord::new(0.0, 1.0), 4).unwrap();
+/// assert_eq!(holes.len(), 4);
+/// assert!(holes[2].approx_eq(&Coord { x: -1.0, y: 0.0, z: None, angle: Some(180.0) }, 1e-9));
+/// ```
+#[cfg(feature = "std")]
+pub fn bolt_circle_from_two(h1: &Coord, h2: &Coord, num: u32) -> Option<Vec<Coord>> {
+    let (dx, dy) = (h2.x - h1.x, h2.y - h1.y);
+    let chord = float::sqrt(dx * dx + dy * dy);
+    if num < 2 || chord == 0.0 || !chord.is_finite() {
+        return None;
+    }
+    let rd = chord / (2.0 * float::sin(core::f64::consts::PI / num as f64));
+    // Distance from the chord midpoint to the center, along the left-hand normal.
+    let h = float::sqrt((rd * rd - chord * chord / 4.0).max(0.0));
+    let xc = (h1.x + h2.x) / 2.0 - dy / chord * h;
+    let yc = (h1.y + h2.y) / 2.0 + dx / chord * h;
+    let st_angle = float::atan2(h1.y - yc, h1.x - xc).to_degrees();
+    Some(calc_bolt_circle(2.0 * rd, num, Some(st_angle), Some(xc), Some(yc), None).collect())
+}
+
+impl Coord {
+    /// Creates a point at (x, y) with no depth or angle.
+    pub fn new(x: f64, y: f64) -> Self {
         Coord {
             x,
             y,
             z: None,
-            angle: Some(ang.to_degrees()),
+            angle: None,
+        }
+    }
+
+    /// Returns the straight-line distance to another point.
+    ///
+    /// The distance is 3D when both points have a `z`, and in the XY plane otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use smithy::layout::Coord;
+    /// assert_eq!(Coord::new(0.0, 0.0).distance_to(&Coord::new(3.0, 4.0)), 5.0);
+    /// ```
+    pub fn distance_to(&self, other: &Coord) -> f64 {
+        let (dx, dy) = (other.x - self.x, other.y - self.y);
+        let dz = match (self.z, other.z) {
+            (Some(a), Some(b)) => b - a,
+            _ => 0.0,
+        };
+        float::sqrt(dx * dx + dy * dy + dz * dz)
+    }
+
+    /// Returns the point halfway to another point.
+    ///
+    /// `z` is averaged when both points have one and is `None` otherwise. The midpoint
+    /// has no angle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use smithy::layout::Coord;
+    /// assert_eq!(Coord::new(0.0, 1.0).midpoint(&Coord::new(2.0, 3.0)), Coord::new(1.0, 2.0));
+    /// ```
+    pub fn midpoint(&self, other: &Coord) -> Coord {
+        Coord {
+            x: (self.x + other.x) / 2.0,
+            y: (self.y + other.y) / 2.0,
+            z: match (self.z, other.z) {
+                (Some(a), Some(b)) => Some((a + b) / 2.0),
+                _ => None,
+            },
+            angle: None,
+        }
+    }
+
+    /// Formats the point like `Display`, rounding every value to `precision` decimals.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use smithy::layout::Coord;
+    /// assert_eq!(Coord::new(1.23456, 2.0).pretty(2), "(1.23, 2.00)");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn pretty(&self, precision: usize) -> String {
+        format!("{self:.precision$}")
+    }
+
+    /// Returns true if every field of `self` is within `eps` of `other`.
+    ///
+    /// `z` and `angle` match when both are None or both are within `eps`.
+    pub fn approx_eq(&self, other: &Coord, eps: f64) -> bool {
+        let close = |a: f64, b: f64| (a - b).abs() <= eps;
+        let close_opt = |a: Option<f64>, b: Option<f64>| match (a, b) {
+            (Some(a), Some(b)) => close(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        close(self.x, other.x)
+            && close(self.y, other.y)
+            && close_opt(self.z, other.z)
+            && close_opt(self.angle, other.angle)
+    }
+}
+
+/// Formats the point as `(x, y[, z])`, followed by ` @angle°` when it has an angle.
+///
+/// Values are rounded to the formatter's precision, or 4 decimals when none is given,
+/// so `format!("{c:.2}")` prints two decimals.
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let p = f.precision().unwrap_or(4);
+        write!(f, "({:.p$}, {:.p$}", self.x, self.y)?;
+        if let Some(z) = self.z {
+            write!(f, ", {z:.p$}")?;
+        }
+        write!(f, ")")?;
+        if let Some(angle) = self.angle {
+            write!(f, " @{angle:.p$}°")?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares two patterns point by point within a tolerance.
+///
+/// # Parameters
+///
+/// - `a`, `b`: The patterns to compare.
+/// - `eps`: The largest allowed difference in any field (see `Coord::approx_eq`).
+///
+/// # Returns
+///
+/// Returns true if the patterns have the same length and every pair of points matches.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{approx_vec_eq, calc_bolt_circle};
+/// let a: Vec<_> = calc_bolt_circle(2.0, 4, None, None, None, None).collect();
+/// let b: Vec<_> = calc_bolt_circle(2.0, 4, Some(360.0), None, None, None).collect();
+/// assert!(!approx_vec_eq(&a, &b, 1e-9)); // angles differ by 360
+/// ```
+pub fn approx_vec_eq(a: &[Coord], b: &[Coord], eps: f64) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.approx_eq(b, eps))
+}
+
+/// Asserts that two patterns match point by point within a tolerance.
+///
+/// # Panics
+///
+/// Panics, naming the first mismatched point, if the patterns differ in length or any
+/// pair of points is not within `eps` (see `Coord::approx_eq`).
+#[track_caller]
+pub fn assert_coord_approx(a: &[Coord], b: &[Coord], eps: f64) {
+    assert_eq!(a.len(), b.len(), "patterns differ in length");
+    for (i, (a, b)) in a.iter().zip(b).enumerate() {
+        assert!(
+            a.approx_eq(b, eps),
+            "point {i} differs by more than {eps}: {a:?} != {b:?}"
+        );
+    }
+}
+
+/// The golden angle, 180° × (3 − √5), in degrees.
+const GOLDEN_ANGLE: f64 = 137.507_764_050_037_85;
+
+/// Calculates a Fermat (phyllotaxis) spiral of points filling a disc.
+///
+/// Point `i` is placed at radius `spacing × √i` and angle `i × 137.508°` (the golden
+/// angle), the arrangement of seeds in a sunflower head. Each point covers roughly the
+/// same area, giving the most even distribution of holes over a disc for a given count.
+///
+/// # Parameters
+///
+/// - `num`: Number of points to calculate. The first point is at the center.
+/// - `spacing`: Scale factor setting the distance between neighbouring points.
+/// - `center`: Center of the spiral. Its `z` is carried onto every point.
+///
+/// # Returns
+///
+/// Returns an iterator of `Coord` values, each with its polar angle about the center
+/// in degrees, normalized to `0..360`.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_phyllotaxis, Coord};
+/// let center = Coord { x: 0.0, y: 0.0, z: None, angle: None };
+/// let holes: Vec<_> = calc_phyllotaxis(100, 0.2, center).collect();
+/// assert_eq!(holes.len(), 100);
+/// ```
+pub fn calc_phyllotaxis(num: u32, spacing: f64, center: Coord) -> impl Iterator<Item = Coord> {
+    (0..num).map(move |i| {
+        let ang = float::rem_euclid(i as f64 * GOLDEN_ANGLE, 360.0);
+        let rd = spacing * float::sqrt(i as f64);
+        Coord {
+            z: center.z,
+            ..polar_to_cart(rd, ang, (center.x, center.y))
         }
     })
 }
@@ -63,11 +537,15 @@ pub fn calc_bolt_circle(
 /// `start` value and ending just before or at the `end` value. The spacing between values
 /// is determined by the `step` parameter.
 ///
+/// A negative `step` counts down from a `start` above `end`. A zero step, or one that
+/// moves away from `end`, yields nothing rather than an endless sequence.
+///
 /// # Parameters
 ///
 /// - `start`: The starting value for the spacing.
 /// - `end`: The end limit for the spacing. The iterator will stop at or just before this value.
-/// - `step`: The step size between each value in the sequence.
+/// - `step`: The step size between each value in the sequence, negative for a descending
+///   sequence.
 ///
 /// # Returns
 ///
@@ -83,104 +561,896 @@ pub fn calc_bolt_circle(
 pub fn calc_linear_spacing(start: f64, end: f64, step: f64) -> impl Iterator<Item = f64> {
     (0..)
         .map(move |i| step * i as f64 + start)
-        .take_while(move |&v| v <= end)
+        .take_while(move |&v| {
+            if step > 0.0 {
+                v <= end
+            } else {
+                step < 0.0 && v >= end
+            }
+        })
 }
 
-/// Generates a grid of `Coord` values based on start values, step sizes, and number of positions along each axis,
-/// with alternating directions for each row.
+/// Calculates a given number of evenly spaced points between a start and end value.
 ///
-/// This function returns an iterator that lazily generates `Coord` structures representing a grid.
-/// The `x` values are incremented by `x_step` for even rows, and decremented by `x_step` for odd rows,
-/// creating a back-and-forth pattern. The `y` values are incremented by `y_step`,
-/// with a specified number of positions along each axis.
+/// Both ends are included, so the spacing is `(end - start) / (n - 1)`. The last value
+/// is `end` exactly, which avoids the rounding in a precomputed step that can make
+/// `calc_linear_spacing` drop the final point.
 ///
 /// # Parameters
 ///
-/// - `x_start`: The starting value for the x-axis.
-/// - `x_cnt`: The number of positions along the x-axis.
-/// - `x_step`: The step size between consecutive x values.
-/// - `y_start`: The starting value for the y-axis.
-/// - `y_cnt`: The number of positions along the y-axis.
-/// - `y_step`: The step size between consecutive y values.
+/// - `start`: The first value.
+/// - `end`: The last value. It may be less than `start` for a descending sequence.
+/// - `n`: The number of values. A count of 1 yields only `start`, and 0 yields nothing.
 ///
 /// # Returns
 ///
-/// Returns an iterator of `Coord` structs, each representing a point on the alternating grid.
+/// Returns an iterator of `n` evenly spaced `f64` values from `start` to `end`.
 ///
 /// # Example
 ///
 /// ```rust
-/// use smithy::layout::{calc_alt_grid};
-/// let grid: Vec<_> = calc_alt_grid(0.0, 4, 2.0, 0.0, 4, 1.0).collect();
-/// assert_eq!(grid.len(), 16);
-/// for coord in grid {
-///     println!("X: {}, Y: {}", coord.x, coord.y);
-/// }
-/// // Example output:
-/// // X: 0.0, Y: 0.0
-/// // X: 1.0, Y: 0.0
-/// // X: 2.0, Y: 0.0
-/// // X: 3.0, Y: 0.0
-/// // X: 3.0, Y: 1.0
-/// // X: 2.0, Y: 1.0
-/// // X: 1.0, Y: 1.0
-/// // X: 0.0, Y: 2.0
+/// use smithy::layout::calc_linear_count;
+/// let points: Vec<_> = calc_linear_count(0.0, 1.0, 3).collect();
+/// assert_eq!(points, [0.0, 0.5, 1.0]);
 /// ```
+pub fn calc_linear_count(start: f64, end: f64, n: u32) -> impl Iterator<Item = f64> {
+    (0..n).map(move |i| match i {
+        0 => start,
+        i if i == n - 1 => end,
+        i => start + (end - start) * i as f64 / (n - 1) as f64,
+    })
+}
 
-pub fn calc_alt_grid(
-    x_start: f64,
-    x_cnt: u32,
-    x_step: f64,
-    y_start: f64,
-    y_cnt: u32,
-    y_step: f64,
+/// Calculates points along an Archimedean spiral.
+///
+/// The radius grows linearly with the angle, by `spacing` every revolution:
+///
+/// ```markdown
+/// r = start_radius + spacing × θ / 360°
+/// ```
+///
+/// The spiral winds counter-clockwise from the +X direction. Points are spaced evenly in
+/// angle, and both the first point and the point at the end of the last (possibly
+/// partial) revolution are included when the end falls on a step.
+///
+/// # Parameters
+///
+/// - `center`: The (x, y) center of the spiral.
+/// - `start_radius`: The radius of the first point; 0 starts at the center.
+/// - `spacing`: The radial distance between successive turns.
+/// - `points_per_rev`: The number of points per revolution.
+/// - `revolutions`: The number of turns, which may be fractional.
+///
+/// # Returns
+///
+/// Returns an iterator that yields `Coord` values with the polar angle of each point, in
+/// degrees within 0..360, in the `angle` field.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::calc_spiral;
+/// let points: Vec<_> = calc_spiral((0.0, 0.0), 0.0, 1.0, 8, 2.0).collect();
+/// assert_eq!(points.len(), 17);
+/// assert_eq!((points[0].x, points[0].y), (0.0, 0.0));
+/// ```
+pub fn calc_spiral(
+    center: (f64, f64),
+    start_radius: f64,
+    spacing: f64,
+    points_per_rev: u32,
+    revolutions: f64,
 ) -> impl Iterator<Item = Coord> {
-    let mut cur_x = 0;
-    let mut cur_y = 0;
+    // Allow for round-off in the number of steps so an exact end is still included.
+    let steps = (points_per_rev as f64 * revolutions + 1e-9) as u32;
+    let count = if points_per_rev == 0 { 0 } else { steps + 1 };
+    (0..count).map(move |i| {
+        let turns = i as f64 / points_per_rev as f64;
+        let rd = start_radius + spacing * turns;
+        polar_to_cart(rd, float::rem_euclid(turns * 360.0, 360.0), center)
+    })
+}
 
-    iter::from_fn(move || {
-        if cur_y >= y_cnt {
-            return None;
+/// Calculates points along a helix for helical interpolation of a bore or thread.
+///
+/// The points lie on a circle like `calc_bolt_circle`, while the depth falls by `pitch`
+/// every revolution:
+///
+/// ```markdown
+/// z = z_start - pitch × θ / 360°
+/// ```
+///
+/// The helix winds counter-clockwise from the +X direction. Like `calc_spiral`, both the
+/// first point and the point at the end of the last (possibly partial) revolution are
+/// included when the end falls on a step.
+///
+/// # Parameters
+///
+/// - `dia`: The diameter of the helix, i.e. the diameter of the tool center path.
+/// - `pitch`: The drop in depth per revolution; negative to climb.
+/// - `revolutions`: The number of turns, which may be fractional.
+/// - `points_per_rev`: The number of points per revolution.
+/// - `center`: The (x, y) center of the helix.
+/// - `z_start`: The depth of the first point.
+///
+/// # Returns
+///
+/// Returns an iterator that yields `Coord` values with `z` set and the angle of each
+/// point in the `angle` field. The angle keeps counting past 360 degrees, so it is the
+/// total angle turned from the start.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::calc_helix;
+/// let points: Vec<_> = calc_helix(1.0, 0.1, 2.0, 4, (0.0, 0.0), 0.0).collect();
+/// assert_eq!(points.len(), 9);
+/// assert_eq!(points[8].angle, Some(720.0));
+/// ```
+pub fn calc_helix(
+    dia: f64,
+    pitch: f64,
+    revolutions: f64,
+    points_per_rev: u32,
+    center: (f64, f64),
+    z_start: f64,
+) -> impl Iterator<Item = Coord> {
+    // Allow for round-off in the number of steps so an exact end is still included.
+    let steps = (points_per_rev as f64 * revolutions + 1e-9) as u32;
+    let count = if points_per_rev == 0 { 0 } else { steps + 1 };
+    (0..count).map(move |i| {
+        let turns = i as f64 / points_per_rev as f64;
+        Coord {
+            z: Some(z_start - pitch * turns),
+            ..polar_to_cart(dia / 2.0, turns * 360.0, center)
         }
+    })
+}
 
-        let x = if cur_y % 2 == 0 {
-            x_start + cur_x as f64 * x_step
-        } else {
-            ((x_cnt - 1) - cur_x) as f64 * x_step
-        };
-        // let x = x_start + cur_x as f64 * x_step;
-        let y = y_start + cur_y as f64 * y_step;
-
-        cur_x += 1;
-
-        if cur_x >= x_cnt {
-            cur_x = 0;
-            cur_y += 1;
-        }
+/// Angular step in degrees between the points of `pocket_spiral`.
+const POCKET_STEP_DEG: f64 = 10.0;
 
-        let coord = Coord {
-            x,
-            y,
-            z: None,
-            angle: None,
+/// Calculates a spiral-out toolpath that clears a round pocket.
+///
+/// The tool center starts at the pocket center, where a helical entry (see `calc_helix`)
+/// brings it to depth, and spirals out counter-clockwise by `stepover` each revolution
+/// until the tool edge reaches the pocket wall. A full circle at that radius then
+/// cleans up the wall:
+///
+/// ```markdown
+/// r max = (pocket_dia - tool_dia) / 2
+/// ```
+///
+/// Points are 10° apart, or slightly less on the spiral so it ends exactly at `r max`.
+///
+/// # Parameters
+///
+/// - `pocket_dia`: The finished diameter of the pocket.
+/// - `tool_dia`: The diameter of the end mill.
+/// - `stepover`: The radial distance between successive turns; keep it below the tool
+///   diameter so no material is left between passes.
+/// - `center`: The (x, y) center of the pocket.
+///
+/// # Returns
+///
+/// Returns an iterator that yields the tool center positions, with the polar angle of
+/// each point, in degrees within 0..360, in the `angle` field. A tool the size of the
+/// pocket yields only the center, and a larger tool or a stepover of zero or less
+/// yields nothing.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{cart_to_polar, pocket_spiral};
+/// let last = pocket_spiral(2.0, 0.5, 0.2, (0.0, 0.0)).last().unwrap();
+/// let (r, _) = cart_to_polar(&last, (0.0, 0.0));
+/// assert!((r - 0.75).abs() < 1e-12);
+/// ```
+pub fn pocket_spiral(
+    pocket_dia: f64,
+    tool_dia: f64,
+    stepover: f64,
+    center: (f64, f64),
+) -> impl Iterator<Item = Coord> {
+    let r_max = (pocket_dia - tool_dia) / 2.0;
+    let valid = r_max >= 0.0 && stepover > 0.0;
+    // Total angle the spiral turns through to reach the wall.
+    let end = if valid { 360.0 * r_max / stepover } else { 0.0 };
+    let steps = float::ceil(end / POCKET_STEP_DEG) as u32;
+    let spiral_count = if valid { steps + 1 } else { 0 };
+    let circle_count = if valid && r_max > 0.0 {
+        (360.0 / POCKET_STEP_DEG) as u32
+    } else {
+        0
+    };
+    let spiral = (0..spiral_count).map(move |i| {
+        let t = if steps == 0 {
+            0.0
+        } else {
+            i as f64 / steps as f64
+        };
+        polar_to_cart(r_max * t, float::rem_euclid(end * t, 360.0), center)
+    });
+    let circle = (1..=circle_count).map(move |i| {
+        let ang = end + i as f64 * POCKET_STEP_DEG;
+        polar_to_cart(r_max, float::rem_euclid(ang, 360.0), center)
+    });
+    spiral.chain(circle)
+}
+
+/// Shared generator for the rectangular grids.
+///
+/// Rows are produced in +y order. With `serpentine` set, odd rows run back in the -x
+/// direction; otherwise every row runs in +x. Odd rows are shifted along x by
+/// `odd_row_shift`.
+#[allow(clippy::too_many_arguments)]
+fn grid(
+    x_start: f64,
+    x_cnt: u32,
+    x_step: f64,
+    y_start: f64,
+    y_cnt: u32,
+    y_step: f64,
+    serpentine: bool,
+    odd_row_shift: f64,
+) -> impl Iterator<Item = Coord> {
+    (0..y_cnt).flat_map(move |row| {
+        let odd = row % 2 == 1;
+        let shift = if odd { odd_row_shift } else { 0.0 };
+        (0..x_cnt).map(move |i| {
+            let col = if odd && serpentine { x_cnt - 1 - i } else { i };
+            Coord {
+                x: x_start + shift + col as f64 * x_step,
+                y: y_start + row as f64 * y_step,
+                z: None,
+                angle: None,
+            }
+        })
+    })
+}
+
+/// Generates a raster grid of `Coord` values where every row runs in the same direction.
+///
+/// Unlike `calc_alt_grid`, each row starts again at `x_start` and runs in the +x
+/// direction. Use this for processes that are sensitive to the direction of travel, such
+/// as probing.
+///
+/// # Parameters
+///
+/// - `x_start`: The starting value for the x-axis.
+/// - `x_cnt`: The number of positions along the x-axis.
+/// - `x_step`: The step size between consecutive x values.
+/// - `y_start`: The starting value for the y-axis.
+/// - `y_cnt`: The number of positions along the y-axis.
+/// - `y_step`: The step size between consecutive y values.
+///
+/// # Returns
+///
+/// Returns an iterator of `Coord` structs, row by row.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::calc_grid;
+/// let grid: Vec<_> = calc_grid(0.0, 3, 1.0, 0.0, 2, 1.0).collect();
+/// assert_eq!((grid[3].x, grid[3].y), (0.0, 1.0));
+/// ```
+pub fn calc_grid(
+    x_start: f64,
+    x_cnt: u32,
+    x_step: f64,
+    y_start: f64,
+    y_cnt: u32,
+    y_step: f64,
+) -> impl Iterator<Item = Coord> {
+    grid(x_start, x_cnt, x_step, y_start, y_cnt, y_step, false, 0.0)
+}
+
+/// Generates a grid of `Coord` values based on start values, step sizes, and number of positions along each axis,
+/// with alternating directions for each row.
+///
+/// This function returns an iterator that lazily generates `Coord` structures representing a grid.
+/// The `x` values are incremented by `x_step` for even rows, and decremented by `x_step` for odd rows,
+/// creating a back-and-forth pattern. The `y` values are incremented by `y_step`,
+/// with a specified number of positions along each axis.
+///
+/// # Parameters
+///
+/// - `x_start`: The starting value for the x-axis.
+/// - `x_cnt`: The number of positions along the x-axis.
+/// - `x_step`: The step size between consecutive x values.
+/// - `y_start`: The starting value for the y-axis.
+/// - `y_cnt`: The number of positions along the y-axis.
+/// - `y_step`: The step size between consecutive y values.
+///
+/// # Returns
+///
+/// Returns an iterator of `Coord` structs, each representing a point on the alternating grid.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_alt_grid};
+/// let grid: Vec<_> = calc_alt_grid(0.0, 4, 2.0, 0.0, 4, 1.0).collect();
+/// assert_eq!(grid.len(), 16);
+/// for coord in grid {
+///     println!("X: {}, Y: {}", coord.x, coord.y);
+/// }
+/// // Example output:
+/// // X: 0.0, Y: 0.0
+/// // X: 1.0, Y: 0.0
+/// // X: 2.0, Y: 0.0
+/// // X: 3.0, Y: 0.0
+/// // X: 3.0, Y: 1.0
+/// // X: 2.0, Y: 1.0
+/// // X: 1.0, Y: 1.0
+/// // X: 0.0, Y: 2.0
+/// ```
+pub fn calc_alt_grid(
+    x_start: f64,
+    x_cnt: u32,
+    x_step: f64,
+    y_start: f64,
+    y_cnt: u32,
+    y_step: f64,
+) -> impl Iterator<Item = Coord> {
+    grid(x_start, x_cnt, x_step, y_start, y_cnt, y_step, true, 0.0)
+}
+
+/// Generates a hexagonal (staggered) grid of `Coord` values.
+///
+/// Every odd row is shifted along x by half of `x_step`, so holes in neighbouring rows
+/// nest between each other. For a true close-packed pattern with equal spacing in every
+/// direction, use a `y_step` of `x_step × √3 / 2`. Offset rows keep the same number of
+/// positions, so they extend half a step past the even rows on the right. Every row runs
+/// in the +x direction.
+///
+/// # Parameters
+///
+/// - `x_start`: The x value of the first point in the even rows.
+/// - `x_cnt`: The number of positions in each row.
+/// - `x_step`: The spacing between positions in a row.
+/// - `y_start`: The y value of the first row.
+/// - `y_cnt`: The number of rows.
+/// - `y_step`: The spacing between rows.
+///
+/// # Returns
+///
+/// Returns an iterator of `Coord` structs, row by row.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::calc_hex_grid;
+/// let grid: Vec<_> = calc_hex_grid(0.0, 3, 1.0, 0.0, 2, 0.866).collect();
+/// assert_eq!(grid.len(), 6);
+/// assert_eq!(grid[3].x, 0.5);
+/// ```
+pub fn calc_hex_grid(
+    x_start: f64,
+    x_cnt: u32,
+    x_step: f64,
+    y_start: f64,
+    y_cnt: u32,
+    y_step: f64,
+) -> impl Iterator<Item = Coord> {
+    grid(
+        x_start,
+        x_cnt,
+        x_step,
+        y_start,
+        y_cnt,
+        y_step,
+        false,
+        x_step / 2.0,
+    )
+}
+
+/// Generates a serpentine grid that fills a rectangular area at the given spacing.
+///
+/// The number of positions along each axis is derived from the size of the area and the
+/// step, including both edges when the size is an exact multiple of the step. Points are
+/// produced in the same back-and-forth order as `calc_alt_grid`, starting at `origin`.
+///
+/// # Parameters
+///
+/// - `width`: The size of the area along the x-axis.
+/// - `height`: The size of the area along the y-axis.
+/// - `x_step`: The spacing between positions along the x-axis.
+/// - `y_step`: The spacing between positions along the y-axis.
+/// - `origin`: The (x, y) position of the first point, at a corner of the area.
+///
+/// # Returns
+///
+/// Returns an iterator of `Coord` structs covering the area in serpentine order. The
+/// iterator is empty if a size or step is not a positive, finite number.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::calc_alt_grid_by_size;
+/// let grid: Vec<_> = calc_alt_grid_by_size(10.0, 6.0, 2.0, 2.0, (0.0, 0.0)).collect();
+/// assert_eq!(grid.len(), 24);
+/// ```
+pub fn calc_alt_grid_by_size(
+    width: f64,
+    height: f64,
+    x_step: f64,
+    y_step: f64,
+    origin: (f64, f64),
+) -> impl Iterator<Item = Coord> {
+    let valid = [width, height, x_step, y_step]
+        .iter()
+        .all(|v| v.is_finite() && *v > 0.0);
+    let (x_cnt, y_cnt) = if valid {
+        // Allow for float error so an exact multiple of the step keeps its far edge.
+        (
+            ((width / x_step + 1e-9) as u32).saturating_add(1),
+            ((height / y_step + 1e-9) as u32).saturating_add(1),
+        )
+    } else {
+        (0, 0)
+    };
+    calc_alt_grid(origin.0, x_cnt, x_step, origin.1, y_cnt, y_step)
+}
+
+/// Interleaves two coordinate streams, alternating one point from each.
+///
+/// Points are taken from `a` and `b` in turn, starting with `a`. When one stream runs
+/// out, the remaining points of the other are yielded in order, so no points are lost
+/// when the patterns have different lengths.
+///
+/// # Parameters
+///
+/// - `a`: The first pattern, which supplies the first point.
+/// - `b`: The second pattern.
+///
+/// # Returns
+///
+/// Returns an iterator of `Coord` values alternating between `a` and `b`.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_bolt_circle, interleave};
+/// let inner = calc_bolt_circle(2.0, 3, None, None, None, None);
+/// let outer = calc_bolt_circle(4.0, 6, None, None, None, None);
+/// assert_eq!(interleave(inner, outer).count(), 9);
+/// ```
+pub fn interleave(
+    a: impl Iterator<Item = Coord>,
+    b: impl Iterator<Item = Coord>,
+) -> impl Iterator<Item = Coord> {
+    let mut a = a.fuse();
+    let mut b = b.fuse();
+    let mut take_a = true;
+
+    iter::from_fn(move || {
+        let next = if take_a {
+            a.next().or_else(|| b.next())
+        } else {
+            b.next().or_else(|| a.next())
+        };
+        take_a = !take_a;
+        next
+    })
+}
+
+/// Closes a path by repeating its first point at the end.
+///
+/// All points are yielded unchanged, followed by a copy of the first point so a contour
+/// returns to where it started. An empty path stays empty.
+///
+/// # Parameters
+///
+/// - `points`: The points of the open path.
+///
+/// # Returns
+///
+/// Returns an iterator of `Coord` values ending at the starting point.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_bolt_circle, close_path};
+/// let contour: Vec<_> = close_path(calc_bolt_circle(2.0, 6, None, None, None, None)).collect();
+/// assert_eq!(contour.len(), 7);
+/// assert_eq!(contour[0], contour[6]);
+/// ```
+pub fn close_path(points: impl Iterator<Item = Coord>) -> impl Iterator<Item = Coord> {
+    let mut points = points.fuse();
+    let mut first = None;
+
+    iter::from_fn(move || match points.next() {
+        Some(c) => {
+            if first.is_none() {
+                first = Some(c.clone());
+            }
+            Some(c)
+        }
+        None => first.take(),
+    })
+}
+
+/// Rotates a pattern about a pivot point.
+///
+/// Each point is rotated counter-clockwise by `angle` about `pivot`, and `angle` is
+/// added to its stored `angle` when it has one, normalized to 0..360 degrees. `z` passes
+/// through unchanged. This is a shorthand for the matching `geometry::Transform2D`.
+///
+/// # Parameters
+///
+/// - `coords`: The points to rotate.
+/// - `angle`: The rotation, counter-clockwise positive; a bare `f64` is in degrees.
+/// - `pivot`: The (x, y) point to rotate about.
+///
+/// # Returns
+///
+/// Returns an iterator that yields the rotated points.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_alt_grid, rotate_coords};
+/// let rotated: Vec<_> = rotate_coords(calc_alt_grid(0.0, 2, 1.0, 0.0, 1, 1.0), 180.0, (0.0, 0.0)).collect();
+/// assert!((rotated[1].x + 1.0).abs() < 1e-12);
+/// ```
+pub fn rotate_coords(
+    coords: impl Iterator<Item = Coord>,
+    angle: impl Into<Angle>,
+    pivot: (f64, f64),
+) -> impl Iterator<Item = Coord> {
+    let (px, py) = pivot;
+    Transform2D::translate(-px, -py)
+        .compose(Transform2D::rotate(angle.into().as_degrees()))
+        .compose(Transform2D::translate(px, py))
+        .apply_all(coords)
+}
+
+/// Moves a pattern by a fixed offset.
+///
+/// `dz` is only applied to points that have a `z`; points without a depth keep `None`.
+///
+/// # Parameters
+///
+/// - `coords`: The points to move.
+/// - `dx`, `dy`: The offset along X and Y.
+/// - `dz`: Optional offset along Z.
+///
+/// # Returns
+///
+/// Returns an iterator that yields the moved points.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_bolt_circle, translate_coords};
+/// let moved: Vec<_> = translate_coords(calc_bolt_circle(2.0, 4, None, None, None, None), 5.0, 2.0, None).collect();
+/// assert_eq!((moved[0].x, moved[0].y), (6.0, 2.0));
+/// ```
+pub fn translate_coords(
+    coords: impl Iterator<Item = Coord>,
+    dx: f64,
+    dy: f64,
+    dz: Option<f64>,
+) -> impl Iterator<Item = Coord> {
+    coords.map(move |c| Coord {
+        x: c.x + dx,
+        y: c.y + dy,
+        z: c.z.map(|z| z + dz.unwrap_or_default()),
+        angle: c.angle,
+    })
+}
+
+/// Mirrors a pattern across the vertical or horizontal axis through the origin.
+///
+/// Mirroring across the Y axis (`MirrorAxis::Y`) negates x and mirroring across the X
+/// axis (`MirrorAxis::X`) negates y, the same meaning as `geometry::mirror_array`. The
+/// stored `angle` is reflected with the point and kept within 0..360 degrees, so a point
+/// at 30° mirrors to 150° across the Y axis and to 330° across the X axis. This is a
+/// shorthand for `geometry::Transform2D::mirror` at position 0.
+///
+/// # Parameters
+///
+/// - `coords`: The points to mirror.
+/// - `axis`: The axis to mirror across.
+///
+/// # Returns
+///
+/// Returns an iterator that yields the mirrored points.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::geometry::MirrorAxis;
+/// use smithy::layout::{calc_bolt_circle, mirror_coords};
+/// let mirrored: Vec<_> = mirror_coords(calc_bolt_circle(2.0, 4, None, None, None, None), MirrorAxis::Y).collect();
+/// assert_eq!(mirrored[0].x, -1.0);
+/// assert_eq!(mirrored[0].angle, Some(180.0));
+/// ```
+pub fn mirror_coords(
+    coords: impl Iterator<Item = Coord>,
+    axis: MirrorAxis,
+) -> impl Iterator<Item = Coord> {
+    Transform2D::mirror(axis, 0.0).apply_all(coords)
+}
+
+/// Sets every point of a pattern to the same depth.
+///
+/// Any existing `z` is replaced.
+///
+/// # Parameters
+///
+/// - `coords`: The points to set the depth of.
+/// - `z`: The depth to assign.
+///
+/// # Returns
+///
+/// Returns an iterator that yields the points with `z` set.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_bolt_circle, with_depth};
+/// let holes: Vec<_> = with_depth(calc_bolt_circle(2.0, 4, None, None, None, None), -0.25).collect();
+/// assert!(holes.iter().all(|c| c.z == Some(-0.25)));
+/// ```
+pub fn with_depth(coords: impl Iterator<Item = Coord>, z: f64) -> impl Iterator<Item = Coord> {
+    coords.map(move |c| Coord { z: Some(z), ..c })
+}
+
+/// Assigns a depth to each point of a pattern that changes by a fixed step per point.
+///
+/// The first point gets `z_start`, the second `z_start + z_step`, and so on, which suits
+/// stepping down on each hole of a helical-style pass or engraving a pattern in layers.
+/// Any existing `z` is replaced.
+///
+/// # Parameters
+///
+/// - `coords`: The points to set the depth of.
+/// - `z_start`: The depth of the first point.
+/// - `z_step`: The change in depth from one point to the next; negative to step down.
+///
+/// # Returns
+///
+/// Returns an iterator that yields the points with `z` set.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_bolt_circle, with_ramp};
+/// let holes: Vec<_> = with_ramp(calc_bolt_circle(2.0, 4, None, None, None, None), 0.0, -0.5).collect();
+/// assert_eq!(holes[3].z, Some(-1.5));
+/// ```
+pub fn with_ramp(
+    coords: impl Iterator<Item = Coord>,
+    z_start: f64,
+    z_step: f64,
+) -> impl Iterator<Item = Coord> {
+    coords.enumerate().map(move |(i, c)| Coord {
+        z: Some(z_start + i as f64 * z_step),
+        ..c
+    })
+}
+
+/// Rounds every value of every point in a pattern to a number of decimal places.
+///
+/// x, y, and `z` and `angle` when present, are rounded with `math::round`. Use it to
+/// clear floating-point noise such as `6.123233995736766e-17` before exporting a
+/// pattern.
+///
+/// # Parameters
+///
+/// - `coords`: The points to round.
+/// - `precision`: The number of decimal places to keep.
+///
+/// # Returns
+///
+/// Returns an iterator that yields the rounded points.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_bolt_circle, round_coords};
+/// let holes: Vec<_> = round_coords(calc_bolt_circle(2.0, 4, None, None, None, None), 4).collect();
+/// assert_eq!((holes[1].x, holes[1].y), (0.0, 1.0));
+/// ```
+pub fn round_coords(
+    coords: impl Iterator<Item = Coord>,
+    precision: u32,
+) -> impl Iterator<Item = Coord> {
+    let round = move |v: f64| math::round(v, precision);
+    coords.map(move |c| Coord {
+        x: round(c.x),
+        y: round(c.y),
+        z: c.z.map(round),
+        angle: c.angle.map(round),
+    })
+}
+
+/// The extents of a pattern, see `bounding_box`.
+///
+/// `min_z` and `max_z` are only set when every point of the pattern has a `z`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BBox {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+    pub min_z: Option<f64>,
+    pub max_z: Option<f64>,
+}
+
+impl BBox {
+    /// Size of the box along X.
+    pub fn width(&self) -> f64 {
+        self.max_x - self.min_x
+    }
+
+    /// Size of the box along Y.
+    pub fn height(&self) -> f64 {
+        self.max_y - self.min_y
+    }
+
+    /// Size of the box along Z, if the pattern has depths.
+    pub fn depth(&self) -> Option<f64> {
+        Some(self.max_z? - self.min_z?)
+    }
+
+    /// The (x, y) center of the box.
+    pub fn center(&self) -> (f64, f64) {
+        (
+            (self.min_x + self.max_x) / 2.0,
+            (self.min_y + self.max_y) / 2.0,
+        )
+    }
+}
+
+/// Calculates the extents of a pattern.
+///
+/// Use it to check that a pattern fits the stock, the fixture or the machine travels
+/// before generating a program.
+///
+/// # Parameters
+///
+/// - `coords`: The points of the pattern.
+///
+/// # Returns
+///
+/// Returns the smallest `BBox` holding every point, or `None` if there are no points.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{bounding_box, calc_grid};
+/// let bbox = bounding_box(calc_grid(0.0, 3, 1.0, 0.0, 2, 0.5)).unwrap();
+/// assert_eq!((bbox.width(), bbox.height()), (2.0, 0.5));
+/// ```
+pub fn bounding_box(coords: impl Iterator<Item = Coord>) -> Option<BBox> {
+    coords.fold(None, |bbox, c| {
+        let Some(b) = bbox else {
+            return Some(BBox {
+                min_x: c.x,
+                min_y: c.y,
+                max_x: c.x,
+                max_y: c.y,
+                min_z: c.z,
+                max_z: c.z,
+            });
         };
-        Some(coord)
+        Some(BBox {
+            min_x: b.min_x.min(c.x),
+            min_y: b.min_y.min(c.y),
+            max_x: b.max_x.max(c.x),
+            max_y: b.max_y.max(c.y),
+            min_z: b.min_z.zip(c.z).map(|(a, z)| a.min(z)),
+            max_z: b.max_z.zip(c.z).map(|(a, z)| a.max(z)),
+        })
     })
 }
 
+/// Sorts points counter-clockwise by their angle about a center.
+///
+/// Angles are measured from `start_angle`, so the first point is the one at or just
+/// counter-clockwise of the start direction. The sort is stable: points at the same
+/// angle keep their original relative order.
+///
+/// # Parameters
+///
+/// - `points`: The points to sort in place.
+/// - `center`: The point the angles are measured about, usually the bolt-circle center.
+/// - `start_angle`: The direction that sorts first; a bare `f64` is in degrees.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_bolt_circle, sort_by_angle, Coord};
+/// let mut holes: Vec<_> = calc_bolt_circle(4.0, 4, None, None, None, None).collect();
+/// let center = Coord { x: 0.0, y: 0.0, z: None, angle: None };
+/// sort_by_angle(&mut holes, center, 180.0);
+/// assert_eq!(holes[0].angle, Some(180.0));
+/// ```
+#[cfg(feature = "std")]
+pub fn sort_by_angle(points: &mut [Coord], center: Coord, start_angle: impl Into<Angle>) {
+    let start_angle = start_angle.into().as_degrees();
+    let key = |c: &Coord| {
+        let (_, ang) = cart_to_polar(c, (center.x, center.y));
+        float::rem_euclid(ang.as_degrees() - start_angle, 360.0)
+    };
+    points.sort_by(|a, b| key(a).total_cmp(&key(b)));
+}
+
+/// Groups bolt-circle holes into angular sectors for indexed drilling.
+///
+/// Sector `i` covers angles from `i × sector` up to, but not including,
+/// `(i + 1) × sector`, measured counter-clockwise from +X about `center`. Groups are
+/// returned in sector order and sectors without holes are skipped, so there is never
+/// more than one group per hole. Holes keep their input order within a group.
+///
+/// # Parameters
+///
+/// - `holes`: The hole positions to group.
+/// - `center`: The center of the bolt circle (the indexer axis).
+/// - `sector`: The angular size of each sector; a bare `f64` is in degrees.
+///
+/// # Returns
+///
+/// Returns the holes of each occupied sector, or no groups if `sector` is not a
+/// positive, finite angle.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::{calc_bolt_circle, split_bolt_circle_sectors, Coord};
+/// let holes: Vec<_> = calc_bolt_circle(4.0, 6, None, None, None, None).collect();
+/// let center = Coord { x: 0.0, y: 0.0, z: None, angle: None };
+/// let groups = split_bolt_circle_sectors(&holes, center, 180.0);
+/// assert_eq!(groups.len(), 2);
+/// assert_eq!(groups[0].len(), 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn split_bolt_circle_sectors(
+    holes: &[Coord],
+    center: Coord,
+    sector: impl Into<Angle>,
+) -> Vec<Vec<Coord>> {
+    let sector_deg = sector.into().as_degrees();
+    if !sector_deg.is_finite() || sector_deg <= 0.0 {
+        return Vec::new();
+    }
+    // Allow for round-off so holes laid out exactly on a sector boundary start the next
+    // sector instead of trailing the previous one.
+    const EPS: f64 = 1e-9;
+    let last = (360.0 / sector_deg - EPS).ceil().max(1.0) - 1.0;
+    let mut sectors = holes
+        .iter()
+        .map(|hole| {
+            let (_, ang) = cart_to_polar(hole, (center.x, center.y));
+            let ang = float::rem_euclid(ang.as_degrees() + EPS, 360.0);
+            (float::floor(ang / sector_deg).min(last) as u64, hole)
+        })
+        .collect::<Vec<_>>();
+    // The sort is stable, so holes keep their input order within a sector.
+    sectors.sort_by_key(|(i, _)| *i);
+    sectors
+        .chunk_by(|a, b| a.0 == b.0)
+        .map(|group| group.iter().map(|(_, hole)| (*hole).clone()).collect())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::util::truncate_float;
+    use crate::math;
 
     #[test]
     fn test_calc_bolt_circle() {
-        let actual = calc_bolt_circle(6.0, 5, Some(20.0), None, None)
+        let actual = calc_bolt_circle(6.0, 5, Some(20.0), None, None, None)
             .map(|p| {
                 (
-                    truncate_float(p.angle.unwrap(), 1),
-                    truncate_float(p.x, 4),
-                    truncate_float(p.y, 4),
+                    math::round(p.angle.unwrap(), 1),
+                    math::round(p.x, 4),
+                    math::round(p.y, 4),
                 )
             })
             .collect::<Vec<_>>();
@@ -194,17 +1464,106 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_polar_cart_conversion() {
+        let origin = (0.0, 0.0);
+        for (ang, x, y) in [
+            (0.0, 2.0, 0.0),
+            (90.0, 0.0, 2.0),
+            (180.0, -2.0, 0.0),
+            (270.0, 0.0, -2.0),
+        ] {
+            let p = polar_to_cart(2.0, ang, origin);
+            assert_eq!((math::round(p.x, 9), math::round(p.y, 9)), (x, y));
+            assert_eq!(p.angle, Some(ang));
+            let (r, a) = cart_to_polar(&Coord::new(x, y), origin);
+            assert_eq!((r, a.as_degrees()), (2.0, ang));
+        }
+
+        // Round trip about an offset center, with a negative angle normalized.
+        let center = (3.0, -1.0);
+        let p = polar_to_cart(1.5, -45.0, center);
+        let (r, a) = cart_to_polar(&p, center);
+        assert_eq!(
+            (math::round(r, 9), math::round(a.as_degrees(), 9)),
+            (1.5, 315.0)
+        );
+
+        // Radians are accepted when spelled out.
+        let p = polar_to_cart(2.0, Angle::radians(core::f64::consts::FRAC_PI_2), origin);
+        assert_eq!((math::round(p.x, 9), p.y), (0.0, 2.0));
+        assert_eq!(p.angle, Some(90.0));
+    }
+
+    #[test]
+    fn test_calc_bolt_circle_sweep() {
+        let actual = calc_bolt_circle(10.0, 3, None, None, None, Some(90.0))
+            .map(|p| {
+                (
+                    math::round(p.angle.unwrap(), 6),
+                    math::round(p.x, 4),
+                    math::round(p.y, 4),
+                )
+            })
+            .collect::<Vec<_>>();
+        let expected = vec![(0.0, 5.0, 0.0), (45.0, 3.5355, 3.5355), (90.0, 0.0, 5.0)];
+        assert_eq!(actual, expected);
+
+        // A full sweep doesn't repeat the first point at the end.
+        let full = calc_bolt_circle(4.0, 4, None, None, None, Some(360.0)).collect::<Vec<_>>();
+        let default = calc_bolt_circle(4.0, 4, None, None, None, None).collect::<Vec<_>>();
+        assert_eq!(full, default);
+
+        let clockwise = calc_bolt_circle(4.0, 5, Some(90.0), None, None, Some(-90.0))
+            .map(|p| math::round(p.angle.unwrap(), 6))
+            .collect::<Vec<_>>();
+        assert_eq!(clockwise, vec![90.0, 67.5, 45.0, 22.5, 0.0]);
+
+        let single =
+            calc_bolt_circle(4.0, 1, Some(30.0), None, None, Some(90.0)).collect::<Vec<_>>();
+        assert_eq!(single.len(), 1);
+        assert_eq!(math::round(single[0].angle.unwrap(), 6), 30.0);
+    }
+
     #[test]
     fn test_calc_linear_spacing() {
         let start = 0.5;
         let end = 11.5;
         let actual = calc_linear_spacing(start, end, (end - start) / 4.0)
-            .map(|v| truncate_float(v, 3))
+            .map(|v| math::round(v, 3))
             .collect::<Vec<_>>();
         let expected = vec![0.5, 3.25, 6.0, 8.75, 11.5];
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_calc_linear_spacing_step_direction() {
+        assert_eq!(calc_linear_spacing(0.0, 5.0, 0.0).count(), 0);
+        assert_eq!(calc_linear_spacing(0.0, 5.0, -1.0).count(), 0);
+        assert_eq!(calc_linear_spacing(5.0, 0.0, 1.0).count(), 0);
+        assert_eq!(calc_linear_spacing(0.0, 5.0, f64::NAN).count(), 0);
+
+        let actual = calc_linear_spacing(2.0, 0.0, -0.5).collect::<Vec<_>>();
+        assert_eq!(actual, vec![2.0, 1.5, 1.0, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn test_calc_linear_count() {
+        let actual = calc_linear_count(0.0, 10.0, 5).collect::<Vec<_>>();
+        assert_eq!(actual, vec![0.0, 2.5, 5.0, 7.5, 10.0]);
+
+        // The end is hit exactly even when the spacing is not representable.
+        let actual = calc_linear_count(0.0, 1.0, 11).collect::<Vec<_>>();
+        assert_eq!(actual.len(), 11);
+        assert_eq!(actual[10], 1.0);
+
+        assert_eq!(
+            calc_linear_count(3.0, 10.0, 1).collect::<Vec<_>>(),
+            vec![3.0]
+        );
+        assert_eq!(calc_linear_count(3.0, 10.0, 0).count(), 0);
+    }
+
     #[test]
     fn test_calc_alt_grid() {
         let actual = calc_alt_grid(0.0, 6, 1.0, 0.0, 4, 1.0)
@@ -216,4 +1575,686 @@ mod tests {
         assert_eq!(actual[6], (5.0, 1.0)); // Second row, first value (reversed)
         assert_eq!(actual[23], (0.0, 3.0));
     }
+
+    #[test]
+    fn test_calc_alt_grid_x_start() {
+        // Reversed rows must be offset by x_start as well, so columns line up.
+        let actual = calc_alt_grid(10.0, 4, 2.0, 0.0, 2, 1.0)
+            .map(|c| (c.x, c.y))
+            .collect::<Vec<(f64, f64)>>();
+        assert_eq!(actual[3], (16.0, 0.0)); // First row, last value
+        assert_eq!(actual[4], (16.0, 1.0)); // Second row, first value (reversed)
+        assert_eq!(actual[7], (10.0, 1.0));
+    }
+
+    #[test]
+    fn test_bolt_circle_chord() {
+        assert_eq!(math::round(bolt_circle_chord(2.0, 6), 12), 1.0);
+        assert_eq!(math::round(bolt_circle_chord(4.0, 4), 4), 2.8284);
+        assert_eq!(bolt_circle_chord(4.0, 1), 0.0);
+
+        let holes =
+            calc_bolt_circle(2.0, 6, Some(15.0), Some(3.0), Some(1.0), None).collect::<Vec<_>>();
+        let chords = with_chords(&holes, true).collect::<Vec<_>>();
+        assert_eq!(chords.len(), 6);
+        assert!(chords
+            .iter()
+            .all(|(_, c)| math::round(c.unwrap(), 12) == 1.0));
+        assert_eq!(chords[2].0, holes[2]);
+
+        // Four holes 30° apart on a partial arc: no chord across the open gap.
+        let holes = calc_bolt_circle(2.0, 4, Some(0.0), None, None, Some(90.0)).collect::<Vec<_>>();
+        let chords = with_chords(&holes, false).collect::<Vec<_>>();
+        assert_eq!(chords.len(), 4);
+        let spacing = bolt_circle_chord(2.0, 12);
+        assert!(chords[..3]
+            .iter()
+            .all(|(_, c)| math::round(c.unwrap(), 12) == math::round(spacing, 12)));
+        assert_eq!(chords[3].1, None);
+
+        // Three holes over 90°: 45° apart.
+        let arc = BoltCircle::new().diameter(2.0).count(3).sweep(90.0);
+        let holes = arc.build().collect::<Vec<_>>();
+        assert_eq!(
+            math::round(arc.chord(), 12),
+            math::round(holes[0].distance_to(&holes[1]), 12)
+        );
+        assert_eq!(
+            BoltCircle::new().diameter(2.0).count(6).chord(),
+            bolt_circle_chord(2.0, 6)
+        );
+    }
+
+    #[test]
+    fn test_bolt_circle_builder() {
+        let built = BoltCircle::new()
+            .diameter(6.0)
+            .count(5)
+            .start_angle(20.0)
+            .center(1.0, -2.0)
+            .build()
+            .collect::<Vec<_>>();
+        let free =
+            calc_bolt_circle(6.0, 5, Some(20.0), Some(1.0), Some(-2.0), None).collect::<Vec<_>>();
+        assert_eq!(built, free);
+
+        let arc = BoltCircle::new().diameter(4.0).count(3).sweep(90.0);
+        let free = calc_bolt_circle(4.0, 3, None, None, None, Some(90.0));
+        assert!(arc.build().eq(free));
+
+        assert_eq!(BoltCircle::new().build().count(), 0);
+    }
+
+    #[test]
+    fn test_calc_bolt_circle_point() {
+        // Runs against libm when built with `--no-default-features`.
+        let p = calc_bolt_circle(4.0, 4, Some(30.0), Some(1.0), Some(-1.0), None)
+            .nth(1)
+            .unwrap();
+        assert_eq!(math::round(p.x, 6), 0.0);
+        assert_eq!(math::round(p.y, 6), 0.732051);
+        assert_eq!(math::round(p.angle.unwrap(), 6), 120.0);
+    }
+
+    #[test]
+    fn test_interleave() {
+        let a = calc_linear_spacing(0.0, 1.0, 1.0).map(|x| Coord {
+            x,
+            y: 0.0,
+            z: None,
+            angle: None,
+        });
+        let b = calc_linear_spacing(0.0, 1.0, 1.0).map(|x| Coord {
+            x,
+            y: 1.0,
+            z: None,
+            angle: None,
+        });
+        let actual = interleave(a, b).map(|c| (c.x, c.y)).collect::<Vec<_>>();
+        assert_eq!(actual, vec![(0.0, 0.0), (0.0, 1.0), (1.0, 0.0), (1.0, 1.0)]);
+
+        // The longer pattern is drained once the shorter one runs out.
+        let a = calc_alt_grid(0.0, 1, 1.0, 0.0, 1, 1.0);
+        let b = calc_alt_grid(0.0, 3, 1.0, 5.0, 1, 1.0);
+        let actual = interleave(a, b).map(|c| (c.x, c.y)).collect::<Vec<_>>();
+        assert_eq!(actual, vec![(0.0, 0.0), (0.0, 5.0), (1.0, 5.0), (2.0, 5.0)]);
+    }
+
+    #[test]
+    fn test_calc_grid() {
+        let actual = calc_grid(0.5, 6, 1.0, 0.0, 4, 1.0)
+            .map(|c| (c.x, c.y))
+            .collect::<Vec<(f64, f64)>>();
+        assert_eq!(actual.len(), 24);
+        assert_eq!(actual[5], (5.5, 0.0));
+        // Row 2 starts back at x_start, unlike the alternating grid.
+        assert_eq!(actual[6], (0.5, 1.0));
+        assert_ne!(
+            calc_alt_grid(0.5, 6, 1.0, 0.0, 4, 1.0).nth(6).unwrap().x,
+            0.5
+        );
+        assert_eq!(actual[23], (5.5, 3.0));
+    }
+
+    #[test]
+    fn test_calc_hex_grid() {
+        let actual = calc_hex_grid(1.0, 4, 0.5, 2.0, 3, 0.25)
+            .map(|c| (c.x, c.y))
+            .collect::<Vec<_>>();
+        assert_eq!(actual.len(), 12);
+        assert_eq!(actual[0], (1.0, 2.0));
+        assert_eq!(actual[3], (2.5, 2.0));
+        // First point of row 1 is offset by half a step.
+        assert_eq!(actual[4], (1.25, 2.25));
+        assert_eq!(actual[7], (2.75, 2.25));
+        assert_eq!(actual[8], (1.0, 2.5));
+    }
+
+    #[test]
+    fn test_calc_spiral() {
+        let center = (2.0, -1.0);
+        let radius = |c: &Coord| ((c.x - center.0).powi(2) + (c.y - center.1).powi(2)).sqrt();
+        let points = calc_spiral(center, 0.5, 0.25, 12, 1.5).collect::<Vec<_>>();
+        assert_eq!(points.len(), 19);
+        assert_eq!(math::round(radius(&points[0]), 9), 0.5);
+        // One full revolution later the radius has grown by exactly the spacing.
+        assert_eq!(
+            math::round(radius(&points[12]) - radius(&points[0]), 9),
+            0.25
+        );
+        assert_eq!(math::round(points[12].angle.unwrap(), 9), 0.0);
+        assert_eq!(math::round(points[3].angle.unwrap(), 9), 90.0);
+        assert_eq!(math::round(points[18].angle.unwrap(), 9), 180.0);
+
+        let from_center = calc_spiral(center, 0.0, 0.25, 4, 1.0).collect::<Vec<_>>();
+        assert_eq!((from_center[0].x, from_center[0].y), center);
+        assert_eq!(calc_spiral(center, 0.0, 0.25, 0, 1.0).count(), 0);
+    }
+
+    #[test]
+    fn test_calc_helix() {
+        let center = (1.0, -2.0);
+        let points = calc_helix(0.5, 0.05, 1.5, 16, center, 0.1).collect::<Vec<_>>();
+        assert_eq!(points.len(), 25);
+        assert_eq!(
+            (points[0].x, points[0].y, points[0].z),
+            (1.25, -2.0, Some(0.1))
+        );
+
+        // One revolution later the helix is back over the start, one pitch deeper.
+        let (start, rev) = (&points[0], &points[16]);
+        assert_eq!(math::round(rev.x - start.x, 9), 0.0);
+        assert_eq!(math::round(rev.y - start.y, 9), 0.0);
+        assert_eq!(math::round(start.z.unwrap() - rev.z.unwrap(), 9), 0.05);
+        assert_eq!(rev.angle, Some(360.0));
+        assert_eq!(points[24].angle, Some(540.0));
+
+        assert_eq!(calc_helix(0.5, 0.05, 1.0, 0, center, 0.0).count(), 0);
+    }
+
+    #[test]
+    fn test_pocket_spiral() {
+        let center = (1.0, 2.0);
+        let radius = |c: &Coord| cart_to_polar(c, center).0;
+        let points = pocket_spiral(2.0, 0.5, 0.15, center).collect::<Vec<_>>();
+        assert_eq!((points[0].x, points[0].y), center);
+
+        // The last pass runs at the radius where the tool edge touches the wall.
+        let r_max = (2.0 - 0.5) / 2.0;
+        let outer = points.iter().map(radius).fold(0.0, f64::max);
+        assert_eq!(math::round(outer, 9), r_max);
+        assert_eq!(math::round(radius(points.last().unwrap()), 9), r_max);
+        // 5 turns of 0.15 out to 0.75, then one cleanup circle.
+        assert_eq!(points.len(), 5 * 36 + 1 + 36);
+        assert_eq!(math::round(radius(&points[36]), 9), 0.15);
+        assert!(points
+            .windows(2)
+            .all(|w| radius(&w[1]) >= radius(&w[0]) - 1e-12));
+        assert!(points
+            .iter()
+            .all(|c| (0.0..360.0).contains(&c.angle.unwrap())));
+
+        assert_eq!(pocket_spiral(0.5, 0.5, 0.15, center).count(), 1);
+        assert_eq!(pocket_spiral(0.25, 0.5, 0.15, center).count(), 0);
+        assert_eq!(pocket_spiral(2.0, 0.5, 0.0, center).count(), 0);
+    }
+
+    #[test]
+    fn test_calc_alt_grid_by_size() {
+        let actual = calc_alt_grid_by_size(10.0, 6.0, 2.0, 2.0, (1.0, 2.0))
+            .map(|c| (c.x, c.y))
+            .collect::<Vec<(f64, f64)>>();
+        assert_eq!(actual.len(), 24);
+        assert_eq!(actual[0], (1.0, 2.0));
+        assert_eq!(actual[5], (11.0, 2.0)); // First row, last value
+        assert_eq!(actual[6], (11.0, 4.0)); // Second row starts above the first row's end
+        assert_eq!(actual[11], (1.0, 4.0));
+        assert_eq!(actual[23], (1.0, 8.0));
+
+        let origin = (0.0, 0.0);
+        assert_eq!(
+            calc_alt_grid_by_size(10.0, 6.0, 0.0, 2.0, origin).count(),
+            0
+        );
+        assert_eq!(
+            calc_alt_grid_by_size(10.0, 6.0, 2.0, -2.0, origin).count(),
+            0
+        );
+        assert_eq!(
+            calc_alt_grid_by_size(-10.0, 6.0, 2.0, 2.0, origin).count(),
+            0
+        );
+        assert_eq!(
+            calc_alt_grid_by_size(10.0, 0.0, 2.0, 2.0, origin).count(),
+            0
+        );
+        assert_eq!(
+            calc_alt_grid_by_size(f64::NAN, 6.0, 2.0, 2.0, origin).count(),
+            0
+        );
+        assert_eq!(
+            calc_alt_grid_by_size(10.0, 6.0, f64::INFINITY, 2.0, origin).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_close_path() {
+        let actual = close_path(calc_alt_grid(0.0, 3, 1.0, 0.0, 1, 1.0)).collect::<Vec<_>>();
+        assert_eq!(actual.len(), 4);
+        assert_eq!(actual[3], actual[0]);
+        assert_eq!((actual[2].x, actual[3].x), (2.0, 0.0));
+
+        assert_eq!(close_path(iter::empty()).count(), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sort_by_angle() {
+        let mut holes =
+            calc_bolt_circle(2.0, 8, None, Some(1.0), Some(1.0), None).collect::<Vec<_>>();
+        for i in [0, 3, 5] {
+            holes.swap(i, 7 - i);
+        }
+        let center = Coord {
+            x: 1.0,
+            y: 1.0,
+            z: None,
+            angle: None,
+        };
+        sort_by_angle(&mut holes, center, 45.0);
+        let actual = holes
+            .iter()
+            .map(|c| math::round(c.angle.unwrap(), 1))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            vec![45.0, 90.0, 135.0, 180.0, 225.0, 270.0, 315.0, 0.0]
+        );
+
+        // Coincident points keep their input order.
+        let mut pts = vec![
+            Coord {
+                x: 2.0,
+                y: 0.0,
+                z: Some(1.0),
+                angle: None,
+            },
+            Coord {
+                x: 0.0,
+                y: 1.0,
+                z: None,
+                angle: None,
+            },
+            Coord {
+                x: 1.0,
+                y: 0.0,
+                z: Some(2.0),
+                angle: None,
+            },
+        ];
+        let center = Coord {
+            x: 0.0,
+            y: 0.0,
+            z: None,
+            angle: None,
+        };
+        sort_by_angle(&mut pts, center, 0.0);
+        let actual = pts.iter().map(|c| c.z).collect::<Vec<_>>();
+        assert_eq!(actual, vec![Some(1.0), Some(2.0), None]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_split_bolt_circle_sectors() {
+        let holes = calc_bolt_circle(4.0, 12, None, Some(2.0), Some(3.0), None).collect::<Vec<_>>();
+        let center = Coord {
+            x: 2.0,
+            y: 3.0,
+            z: None,
+            angle: None,
+        };
+        let groups = split_bolt_circle_sectors(&holes, center.clone(), 90.0);
+        assert_eq!(groups.len(), 4);
+        for (i, group) in groups.iter().enumerate() {
+            let angles = group
+                .iter()
+                .map(|c| math::round(c.angle.unwrap(), 1))
+                .collect::<Vec<_>>();
+            let start = i as f64 * 90.0;
+            assert_eq!(angles, vec![start, start + 30.0, start + 60.0]);
+        }
+
+        // Sectors that don't divide the circle evenly leave a short last sector.
+        let groups = split_bolt_circle_sectors(&holes, center.clone(), 100.0);
+        let sizes = groups.iter().map(Vec::len).collect::<Vec<_>>();
+        assert_eq!(sizes, vec![4, 3, 3, 2]);
+
+        // Sectors smaller than the hole spacing leave no empty groups behind.
+        let groups = split_bolt_circle_sectors(&holes, center.clone(), 1e-9);
+        assert_eq!(groups.len(), 12);
+        assert!(groups.iter().all(|g| g.len() == 1));
+        let groups = split_bolt_circle_sectors(&holes, center.clone(), 45.0);
+        assert_eq!(groups.len(), 8);
+
+        assert!(split_bolt_circle_sectors(&holes, center.clone(), 0.0).is_empty());
+        assert!(split_bolt_circle_sectors(&holes, center.clone(), -90.0).is_empty());
+        assert!(split_bolt_circle_sectors(&holes, center.clone(), f64::NAN).is_empty());
+        assert!(split_bolt_circle_sectors(&holes, center, f64::INFINITY).is_empty());
+    }
+
+    #[test]
+    fn test_coord_distance_to() {
+        let a = Coord::new(1.0, 1.0);
+        let b = Coord::new(4.0, 5.0);
+        assert_eq!(a.distance_to(&b), 5.0);
+        assert_eq!(b.distance_to(&a), 5.0);
+
+        // 3D when both have z: a 2-3-6 box diagonal is 7.
+        let a = Coord {
+            z: Some(1.0),
+            ..Coord::new(0.0, 0.0)
+        };
+        let b = Coord {
+            z: Some(7.0),
+            ..Coord::new(2.0, 3.0)
+        };
+        assert_eq!(a.distance_to(&b), 7.0);
+        // Falls back to 2D when either z is missing.
+        assert_eq!(
+            a.distance_to(&Coord::new(3.0, 4.0)),
+            Coord::new(3.0, 4.0).distance_to(&a)
+        );
+        assert_eq!(a.distance_to(&Coord::new(3.0, 4.0)), 5.0);
+    }
+
+    #[test]
+    fn test_coord_midpoint() {
+        let a = Coord {
+            z: Some(-1.0),
+            angle: Some(45.0),
+            ..Coord::new(0.0, 2.0)
+        };
+        let b = Coord {
+            z: Some(-0.5),
+            ..Coord::new(3.0, 4.0)
+        };
+        assert_eq!(
+            a.midpoint(&b),
+            Coord {
+                x: 1.5,
+                y: 3.0,
+                z: Some(-0.75),
+                angle: None,
+            }
+        );
+        assert_eq!(a.midpoint(&Coord::new(3.0, 4.0)).z, None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_coord_serde() {
+        let c = Coord {
+            x: 1.5,
+            y: -2.0,
+            z: None,
+            angle: Some(45.0),
+        };
+        let json = serde_json::to_string(&c).unwrap();
+        assert_eq!(json, r#"{"angle":45.0,"x":1.5,"y":-2.0}"#);
+        assert_eq!(serde_json::from_str::<Coord>(&json).unwrap(), c);
+
+        let c = serde_json::from_str::<Coord>(r#"{"x":1.0,"y":2.0,"z":null}"#).unwrap();
+        assert_eq!(c, Coord::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_coord_display() {
+        let mut c = Coord::new(1.23456, -2.0);
+        assert_eq!(format!("{c}"), "(1.2346, -2.0000)");
+        c.z = Some(-0.125);
+        c.angle = Some(45.0);
+        assert_eq!(format!("{c:.1}"), "(1.2, -2.0, -0.1) @45.0°");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_coord_pretty() {
+        assert_eq!(Coord::new(1.23456, 2.0).pretty(2), "(1.23, 2.00)");
+        assert_eq!(Coord::new(0.5, 0.25).pretty(0), "(0, 0)");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_bolt_circle_from_two() {
+        let expected =
+            calc_bolt_circle(3.5, 6, Some(15.0), Some(2.0), Some(-1.0), None).collect::<Vec<_>>();
+        let actual = bolt_circle_from_two(&expected[0], &expected[1], 6).unwrap();
+        assert_coord_approx(&actual, &expected, 1e-9);
+
+        // Starting from a later hole wraps around the same circle.
+        let actual = bolt_circle_from_two(&expected[4], &expected[5], 6).unwrap();
+        assert!((actual[2].x - expected[0].x).abs() < 1e-9);
+        assert!((actual[2].y - expected[0].y).abs() < 1e-9);
+
+        assert!(bolt_circle_from_two(&expected[0], &expected[0], 6).is_none());
+        assert!(bolt_circle_from_two(&expected[0], &expected[1], 1).is_none());
+    }
+
+    #[test]
+    fn test_rotate_coords() {
+        let p = Coord {
+            x: 1.0,
+            y: 0.0,
+            z: Some(-0.5),
+            angle: Some(0.0),
+        };
+        let rotated = rotate_coords(iter::once(p), 90.0, (0.0, 0.0))
+            .next()
+            .unwrap();
+        assert!(rotated.approx_eq(
+            &Coord {
+                x: 0.0,
+                y: 1.0,
+                z: Some(-0.5),
+                angle: Some(90.0),
+            },
+            1e-12
+        ));
+
+        // About an offset pivot, matching a bolt circle laid out at the new start angle.
+        let holes = calc_bolt_circle(2.0, 4, None, Some(3.0), Some(1.0), None);
+        let actual = rotate_coords(holes, 30.0, (3.0, 1.0)).collect::<Vec<_>>();
+        let expected =
+            calc_bolt_circle(2.0, 4, Some(30.0), Some(3.0), Some(1.0), None).collect::<Vec<_>>();
+        assert_coord_approx(&actual, &expected, 1e-12);
+
+        // The stored angle wraps back into 0..360.
+        let p = polar_to_cart(1.0, 330.0, (0.0, 0.0));
+        let rotated = rotate_coords(iter::once(p), 60.0, (0.0, 0.0))
+            .next()
+            .unwrap();
+        assert_eq!(math::round(rotated.angle.unwrap(), 9), 30.0);
+        let rotated = rotate_coords(iter::once(Coord::new(0.0, 1.0)), -90.0, (0.0, 0.0))
+            .next()
+            .unwrap();
+        assert!(rotated.approx_eq(&Coord::new(1.0, 0.0), 1e-12));
+    }
+
+    #[test]
+    fn test_translate_coords() {
+        let points = [
+            Coord::new(1.0, 2.0),
+            Coord {
+                x: 0.0,
+                y: 0.0,
+                z: Some(-0.25),
+                angle: Some(45.0),
+            },
+        ];
+        let moved = translate_coords(points.into_iter(), 0.5, -1.0, Some(-0.5)).collect::<Vec<_>>();
+        assert_eq!(moved[0], Coord::new(1.5, 1.0));
+        assert_eq!(
+            moved[1],
+            Coord {
+                x: 0.5,
+                y: -1.0,
+                z: Some(-0.75),
+                angle: Some(45.0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let holes = calc_bolt_circle(4.0, 8, None, Some(1.0), Some(2.0), None);
+        let bbox = bounding_box(holes).unwrap();
+        assert_eq!(math::round(bbox.width(), 9), 4.0);
+        assert_eq!(math::round(bbox.height(), 9), 4.0);
+        assert_eq!(bbox.center(), (1.0, 2.0));
+        assert_eq!(bbox.depth(), None);
+
+        let holes = with_ramp(calc_bolt_circle(4.0, 8, None, None, None, None), 0.0, -0.1);
+        let bbox = bounding_box(holes).unwrap();
+        assert_eq!(bbox.min_z.map(|z| math::round(z, 9)), Some(-0.7));
+        assert_eq!(bbox.max_z, Some(0.0));
+
+        // A single point without a depth clears the z extents.
+        let mut holes =
+            with_depth(calc_bolt_circle(4.0, 8, None, None, None, None), -0.25).collect::<Vec<_>>();
+        holes[3].z = None;
+        assert_eq!(bounding_box(holes.into_iter()).unwrap().depth(), None);
+
+        assert_eq!(bounding_box(core::iter::empty()), None);
+    }
+
+    #[test]
+    fn test_round_coords() {
+        let holes = with_depth(
+            calc_bolt_circle(2.5, 7, Some(10.0), Some(0.1), None, None),
+            -0.33333,
+        );
+        let rounded = round_coords(holes, 4).collect::<Vec<_>>();
+        assert_eq!(
+            rounded[1],
+            Coord {
+                x: 0.6978,
+                y: 1.0978,
+                z: Some(-0.3333),
+                angle: Some(61.4286),
+            }
+        );
+        let scale = 10_f64.powi(4);
+        for c in &rounded {
+            for v in [c.x, c.y, c.z.unwrap(), c.angle.unwrap()] {
+                assert_eq!((v * scale).round() / scale, v);
+            }
+        }
+
+        // Missing values stay missing.
+        let plain = round_coords([Coord::new(1.23456, -0.00004)].into_iter(), 4).next();
+        assert_eq!(plain, Some(Coord::new(1.2346, 0.0)));
+    }
+
+    #[test]
+    fn test_with_depth() {
+        let points = [
+            Coord::new(0.0, 0.0),
+            Coord::new(1.0, 0.0),
+            Coord {
+                x: 2.0,
+                y: 0.0,
+                z: Some(0.1),
+                angle: Some(90.0),
+            },
+        ];
+        let flat = with_depth(points.clone().into_iter(), -0.125).collect::<Vec<_>>();
+        assert_eq!(
+            flat.iter().map(|c| c.z).collect::<Vec<_>>(),
+            vec![Some(-0.125); 3]
+        );
+        assert_eq!((flat[2].x, flat[2].angle), (2.0, Some(90.0)));
+
+        let ramp = with_ramp(points.into_iter(), -0.1, -0.05).collect::<Vec<_>>();
+        let depths = ramp
+            .iter()
+            .map(|c| math::round(c.z.unwrap(), 6))
+            .collect::<Vec<_>>();
+        assert_eq!(depths, vec![-0.1, -0.15, -0.2]);
+        assert_eq!(ramp[1].x, 1.0);
+    }
+
+    #[test]
+    fn test_mirror_coords() {
+        // Across the Y axis negates x.
+        let holes = calc_bolt_circle(4.0, 6, Some(30.0), None, None, None);
+        let mirrored = mirror_coords(holes, MirrorAxis::Y).collect::<Vec<_>>();
+        let p = &mirrored[0];
+        assert_eq!(math::round(p.x, 4), -1.7321);
+        assert_eq!(math::round(p.y, 4), 1.0);
+        assert_eq!(math::round(p.angle.unwrap(), 6), 150.0);
+        // The stored angle still matches the mirrored position.
+        let ang = float::atan2(p.y, p.x).to_degrees();
+        assert_eq!(math::round(ang, 6), 150.0);
+
+        // Across the X axis negates y.
+        let holes = calc_bolt_circle(4.0, 6, Some(30.0), None, None, None);
+        let mirrored = mirror_coords(holes, MirrorAxis::X).collect::<Vec<_>>();
+        assert_eq!(math::round(mirrored[0].x, 4), 1.7321);
+        assert_eq!(math::round(mirrored[0].y, 4), -1.0);
+        assert_eq!(math::round(mirrored[0].angle.unwrap(), 6), 330.0);
+
+        // Same meaning as mirror_array about the origin.
+        #[cfg(feature = "std")]
+        for axis in [MirrorAxis::X, MirrorAxis::Y] {
+            let holes = calc_bolt_circle(4.0, 6, Some(20.0), None, None, None).collect::<Vec<_>>();
+            let mirrored = mirror_coords(holes.clone().into_iter(), axis).collect::<Vec<_>>();
+            let full = crate::geometry::mirror_array(&holes, axis, 0.0, 1e-9);
+            assert_coord_approx(&mirrored, &full[holes.len()..], 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_calc_phyllotaxis() {
+        let center = Coord {
+            x: 1.0,
+            y: 2.0,
+            z: None,
+            angle: None,
+        };
+        let pts = calc_phyllotaxis(50, 0.5, center.clone()).collect::<Vec<_>>();
+        assert_eq!(pts.len(), 50);
+        assert_eq!(
+            pts[0],
+            Coord {
+                angle: Some(0.0),
+                ..center
+            }
+        );
+
+        for (i, p) in pts.iter().enumerate() {
+            let rd = ((p.x - 1.0).powi(2) + (p.y - 2.0).powi(2)).sqrt();
+            assert_eq!(math::round(rd, 9), math::round(0.5 * (i as f64).sqrt(), 9));
+        }
+        for w in pts.windows(2) {
+            let step = (w[1].angle.unwrap() - w[0].angle.unwrap()).rem_euclid(360.0);
+            assert_eq!(math::round(step, 6), 137.507764);
+        }
+    }
+
+    #[test]
+    fn test_approx_vec_eq() {
+        let actual = calc_bolt_circle(6.0, 3, Some(20.0), None, None, None).collect::<Vec<_>>();
+        let expected = [
+            (20.0, 2.8191, 1.0261),
+            (140.0, -2.2981, 1.9284),
+            (260.0, -0.5209, -2.9544),
+        ]
+        .map(|(angle, x, y)| Coord {
+            x,
+            y,
+            z: None,
+            angle: Some(angle),
+        });
+        assert!(approx_vec_eq(&actual, &expected, 1e-4));
+        assert_coord_approx(&actual, &expected, 1e-4);
+
+        let mut off = expected.clone();
+        off[1].y += 0.001;
+        assert!(!approx_vec_eq(&actual, &off, 1e-4));
+        assert!(!approx_vec_eq(&actual, &expected[..2], 1e-4));
+
+        let mut with_z = expected.clone();
+        with_z[0].z = Some(0.0);
+        assert!(!approx_vec_eq(&actual, &with_z, 1e-4));
+    }
+
+    #[test]
+    #[should_panic(expected = "point 1 differs")]
+    fn test_assert_coord_approx_fails() {
+        let actual = calc_alt_grid(0.0, 3, 1.0, 0.0, 1, 1.0).collect::<Vec<_>>();
+        let mut expected = actual.clone();
+        expected[1].x += 0.01;
+        assert_coord_approx(&actual, &expected, 0.001);
+    }
 }
@@ -1,3 +1,27 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+pub mod drilling;
+#[cfg(feature = "std")]
+pub mod export;
+pub mod fits;
+mod float;
+#[cfg(feature = "std")]
+pub mod gcode;
+pub mod gears;
+pub mod geometry;
+#[cfg(feature = "std")]
+pub mod heidenhain;
 pub mod layout;
+pub mod math;
+#[cfg(feature = "std")]
+pub mod milling;
+#[cfg(feature = "std")]
+pub mod order;
+pub mod program;
+pub mod setup;
+pub mod speeds;
+pub mod taper;
 pub mod threading;
-mod util;
+pub mod trig;
+pub mod turning;
+pub mod units;
@@ -0,0 +1,162 @@
+use crate::float;
+
+/// Rounds a float to `n` decimal places.
+///
+/// Halves are rounded away from zero; see `round_with` for other directions. This is
+/// built on the crate's `libm`-backed float functions so it is available on embedded
+/// targets.
+///
+/// # Parameters
+/// - f: The value to round.
+/// - n: The number of decimal places to keep.
+///
+/// # Example
+/// ```rust
+/// use smithy::math::round;
+/// assert_eq!(round(0.0011297934537308734, 4), 0.0011);
+/// ```
+pub fn round(f: f64, n: u32) -> f64 {
+    round_with(f, n, RoundMode::Nearest)
+}
+
+/// How `round_with` treats the digits it drops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundMode {
+    /// To the nearest value, with halves rounded away from zero.
+    Nearest,
+    /// Toward zero, i.e. truncation.
+    TowardZero,
+    /// Toward positive infinity.
+    Ceil,
+    /// Toward negative infinity.
+    Floor,
+}
+
+/// Rounds a float to `n` decimal places in the given direction.
+///
+/// `RoundMode::Nearest` gives the same result as `round`. The directed modes suit
+/// conservative tolerancing, e.g. rounding a maximum limit down so it is never exceeded.
+///
+/// # Parameters
+/// - f: The value to round.
+/// - n: The number of decimal places to keep.
+/// - mode: The rounding direction.
+///
+/// # Example
+/// ```rust
+/// use smithy::math::{round_with, RoundMode};
+/// assert_eq!(round_with(0.1299, 3, RoundMode::Nearest), 0.13);
+/// assert_eq!(round_with(0.1299, 3, RoundMode::TowardZero), 0.129);
+/// ```
+pub fn round_with(f: f64, n: u32, mode: RoundMode) -> f64 {
+    let factor = float::powi(10.0, n as i32);
+    let scaled = f * factor;
+    let rounded = match mode {
+        RoundMode::Nearest => float::round(scaled),
+        RoundMode::TowardZero => float::trunc(scaled),
+        RoundMode::Ceil => float::ceil(scaled),
+        RoundMode::Floor => float::floor(scaled),
+    };
+    rounded / factor
+}
+
+/// An angle that keeps track of its unit.
+///
+/// Construct it with [`Angle::degrees`] or [`Angle::radians`] and read it back with
+/// [`Angle::as_degrees`] or [`Angle::as_radians`], so a value in one unit can never be
+/// passed where the other is expected. The value is stored in degrees, the unit used by
+/// `Coord::angle` and the layout functions.
+///
+/// The layout functions take their angles as `impl Into<Angle>`. A bare `f64` converts
+/// as degrees, so existing callers keep working while radians must be spelled out with
+/// [`Angle::radians`].
+///
+/// # Example
+/// ```rust
+/// use smithy::math::Angle;
+/// let a = Angle::radians(core::f64::consts::FRAC_PI_2);
+/// assert_eq!(a.as_degrees(), 90.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Angle(f64);
+
+impl Angle {
+    /// Creates an angle from a value in degrees.
+    pub fn degrees(deg: f64) -> Self {
+        Angle(deg)
+    }
+
+    /// Creates an angle from a value in radians.
+    pub fn radians(rad: f64) -> Self {
+        Angle(rad.to_degrees())
+    }
+
+    /// Returns the angle in degrees.
+    pub fn as_degrees(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the angle in radians.
+    pub fn as_radians(self) -> f64 {
+        self.0.to_radians()
+    }
+
+    /// Returns the same direction within 0..360 degrees.
+    pub fn normalized(self) -> Self {
+        Angle(float::rem_euclid(self.0, 360.0))
+    }
+}
+
+impl From<f64> for Angle {
+    /// Converts a value in degrees.
+    fn from(deg: f64) -> Self {
+        Angle(deg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_round() {
+        assert_eq!(round(0.0011297934537308734, 4), 0.0011);
+        assert_eq!(round(0.001196095376922672, 5), 0.00120);
+        assert_eq!(round(-2.36399, 3), -2.364);
+    }
+
+    #[test]
+    fn test_round_with() {
+        assert_eq!(round_with(0.1299, 3, RoundMode::Nearest), 0.13);
+        assert_eq!(round_with(0.1299, 3, RoundMode::TowardZero), 0.129);
+        assert_eq!(round_with(0.1299, 3, RoundMode::Floor), 0.129);
+        assert_eq!(round_with(0.1291, 3, RoundMode::Ceil), 0.13);
+
+        // Truncation and floor differ below zero.
+        assert_eq!(round_with(-0.1299, 3, RoundMode::TowardZero), -0.129);
+        assert_eq!(round_with(-0.1299, 3, RoundMode::Floor), -0.13);
+        assert_eq!(round_with(-0.1299, 3, RoundMode::Ceil), -0.129);
+
+        assert_eq!(
+            round_with(-2.36399, 3, RoundMode::Nearest),
+            round(-2.36399, 3)
+        );
+    }
+
+    #[test]
+    fn test_angle_conversion() {
+        assert_eq!(Angle::radians(PI).as_degrees(), 180.0);
+        assert_eq!(round(Angle::radians(PI / 6.0).as_degrees(), 9), 30.0);
+        assert_eq!(Angle::degrees(90.0).as_radians(), PI / 2.0);
+        assert_eq!(Angle::degrees(45.0), Angle::radians(PI / 4.0));
+        assert_eq!(Angle::from(30.0), Angle::degrees(30.0));
+    }
+
+    #[test]
+    fn test_angle_normalized() {
+        assert_eq!(Angle::degrees(450.0).normalized().as_degrees(), 90.0);
+        assert_eq!(Angle::degrees(-90.0).normalized().as_degrees(), 270.0);
+        assert_eq!(Angle::degrees(360.0).normalized().as_degrees(), 0.0);
+    }
+}
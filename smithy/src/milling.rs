@@ -0,0 +1,89 @@
+/// Plans the roughing passes for a slot that is wider and deeper than the tool.
+///
+/// The depth is split into equal axial steps no deeper than `max_doc`. At each depth
+/// the first pass runs along one wall of the slot and the tool then steps over in equal
+/// radial steps no wider than `max_woc` until it reaches the opposite wall. The slot is
+/// cleared level by level, so all radial passes at one depth come before the next
+/// step down.
+///
+/// ```markdown
+/// axial passes  = ⌈depth / max_doc⌉
+/// radial passes = ⌈(width - tool_dia) / max_woc⌉ + 1
+/// ```
+///
+/// # Parameters
+/// - slot_width: Finished width of the slot.
+/// - slot_depth: Finished depth of the slot.
+/// - tool_dia: Diameter of the end mill.
+/// - max_doc: Maximum axial depth of cut per pass.
+/// - max_woc: Maximum radial width of cut per stepover.
+///
+/// # Returns
+/// - `Vec<(f64, f64)>`: `(axial_depth, radial_offset)` pairs in cutting order. The axial
+///   depth is measured from the top of the slot, and the radial offset is the distance of
+///   the tool center from its position on the first pass. Returns an empty vector if the
+///   tool is wider than the slot or any input is not positive.
+///
+/// # Example
+/// ```rust
+/// use smithy::milling::plan_slot_passes;
+/// let passes = plan_slot_passes(0.5, 0.25, 0.5, 0.1, 0.25);
+/// assert_eq!(passes.len(), 3);
+/// assert_eq!(passes[2].0, 0.25);
+/// ```
+pub fn plan_slot_passes(
+    slot_width: f64,
+    slot_depth: f64,
+    tool_dia: f64,
+    max_doc: f64,
+    max_woc: f64,
+) -> Vec<(f64, f64)> {
+    if [slot_width, slot_depth, tool_dia, max_doc, max_woc]
+        .iter()
+        .any(|v| v.is_nan() || *v <= 0.0)
+        || tool_dia > slot_width
+    {
+        return Vec::new();
+    }
+    // Allow for round-off so a depth or stepover that is an exact multiple of the limit
+    // doesn't gain an extra sliver pass.
+    const EPS: f64 = 1e-9;
+    let stepover = slot_width - tool_dia;
+    let axial = (slot_depth / max_doc - EPS).ceil().max(1.0) as u32;
+    let radial = (stepover / max_woc - EPS).ceil().max(0.0) as u32 + 1;
+    let mut passes = Vec::with_capacity((axial * radial) as usize);
+    for i in 1..=axial {
+        let depth = slot_depth * i as f64 / axial as f64;
+        for j in 0..radial {
+            let offset = if radial > 1 {
+                stepover * j as f64 / (radial - 1) as f64
+            } else {
+                0.0
+            };
+            passes.push((depth, offset));
+        }
+    }
+    passes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_slot_passes() {
+        let passes = plan_slot_passes(1.0, 0.4, 0.5, 0.2, 0.5);
+        assert_eq!(passes, vec![(0.2, 0.0), (0.2, 0.5), (0.4, 0.0), (0.4, 0.5)]);
+
+        // Uneven depth is split into equal steps under the limit.
+        let passes = plan_slot_passes(0.5, 0.25, 0.5, 0.1, 0.25);
+        let depths = passes.iter().map(|p| p.0).collect::<Vec<_>>();
+        assert_eq!(depths.len(), 3);
+        assert!((depths[0] - 0.25 / 3.0).abs() < 1e-12);
+        assert_eq!(depths[2], 0.25);
+        assert!(passes.iter().all(|p| p.1 == 0.0));
+
+        assert!(plan_slot_passes(0.25, 0.5, 0.5, 0.1, 0.1).is_empty());
+        assert!(plan_slot_passes(1.0, 0.5, 0.5, 0.0, 0.1).is_empty());
+    }
+}
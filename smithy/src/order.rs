@@ -0,0 +1,221 @@
+use crate::layout::Coord;
+
+/// How `prepare_pattern` reorders points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReorderMode {
+    /// Keep the input order.
+    Keep,
+    /// Greedy nearest-neighbor tour starting from the origin.
+    NearestNeighbor,
+    /// Rows of increasing y, alternating direction along x. Points whose y values are
+    /// within `row_tol` of the first point of a row belong to that row.
+    Serpentine { row_tol: f64 },
+}
+
+/// Orders points by repeatedly moving to the nearest unvisited point.
+///
+/// Ties are broken by input order, so the result is deterministic.
+pub(crate) fn nearest_neighbor(mut points: Vec<Coord>, start: (f64, f64)) -> Vec<Coord> {
+    let mut ordered = Vec::with_capacity(points.len());
+    let (mut x, mut y) = start;
+    while !points.is_empty() {
+        let dist = |c: &Coord| (c.x - x) * (c.x - x) + (c.y - y) * (c.y - y);
+        let (next, _) = points
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| dist(a).total_cmp(&dist(b)))
+            .unwrap();
+        let p = points.remove(next);
+        (x, y) = (p.x, p.y);
+        ordered.push(p);
+    }
+    ordered
+}
+
+/// Orders points into rows of increasing y, alternating the x direction of each row.
+pub(crate) fn serpentine_order(mut points: Vec<Coord>, row_tol: f64) -> Vec<Coord> {
+    points.sort_by(|a, b| a.y.total_cmp(&b.y));
+    let mut ordered = Vec::with_capacity(points.len());
+    let mut rest = &mut points[..];
+    let mut reverse = false;
+    while let Some(first) = rest.first() {
+        let row_y = first.y;
+        let len = rest
+            .iter()
+            .position(|c| c.y - row_y > row_tol)
+            .unwrap_or(rest.len());
+        let (row, tail) = rest.split_at_mut(len);
+        if reverse {
+            row.sort_by(|a, b| b.x.total_cmp(&a.x));
+        } else {
+            row.sort_by(|a, b| a.x.total_cmp(&b.x));
+        }
+        ordered.extend_from_slice(row);
+        rest = tail;
+        reverse = !reverse;
+    }
+    ordered
+}
+
+/// Reorders holes to cut down on rapid travel between them.
+///
+/// Uses the greedy nearest-neighbor heuristic: starting from `start`, the next hole is
+/// always the closest one not yet visited. The result is not guaranteed to be the
+/// shortest path (it can leave a long jump back at the end), but it is deterministic:
+/// ties go to the hole listed first, so the same input always gives the same order.
+/// The cost grows with the square of the number of holes.
+///
+/// # Parameters
+///
+/// - `coords`: The holes to reorder.
+/// - `start`: The (x, y) position of the tool before the first hole.
+///
+/// # Returns
+///
+/// Returns the same holes in travel order.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::layout::Coord;
+/// use smithy::order::optimize_order;
+/// let holes = vec![Coord::new(2.0, 0.0), Coord::new(0.0, 0.0), Coord::new(1.0, 0.0)];
+/// let ordered = optimize_order(holes, (0.0, 0.0));
+/// assert_eq!(ordered.iter().map(|c| c.x).collect::<Vec<_>>(), [0.0, 1.0, 2.0]);
+/// ```
+pub fn optimize_order(coords: Vec<Coord>, start: (f64, f64)) -> Vec<Coord> {
+    nearest_neighbor(coords, start)
+}
+
+/// Cleans up an imported list of hole positions.
+///
+/// Converts `(x, y)` pairs into `Coord` values, drops any pair that is not finite (NaN
+/// or infinite, as produced by blank or garbled cells) and reorders what is left for
+/// efficient travel.
+///
+/// # Parameters
+///
+/// - `raw`: The imported (x, y) positions.
+/// - `reorder`: How to reorder the valid points.
+///
+/// # Returns
+///
+/// Returns the valid points as `Coord` values in the requested order.
+///
+/// # Example
+///
+/// ```rust
+/// use smithy::order::{prepare_pattern, ReorderMode};
+/// let raw = [(1.0, 0.0), (f64::NAN, 2.0), (0.0, 0.0)];
+/// let holes = prepare_pattern(&raw, ReorderMode::NearestNeighbor);
+/// assert_eq!(holes.len(), 2);
+/// assert_eq!(holes[0].x, 0.0);
+/// ```
+pub fn prepare_pattern(raw: &[(f64, f64)], reorder: ReorderMode) -> Vec<Coord> {
+    let points = raw
+        .iter()
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .map(|&(x, y)| Coord {
+            x,
+            y,
+            z: None,
+            angle: None,
+        })
+        .collect();
+    match reorder {
+        ReorderMode::Keep => points,
+        ReorderMode::NearestNeighbor => nearest_neighbor(points, (0.0, 0.0)),
+        ReorderMode::Serpentine { row_tol } => serpentine_order(points, row_tol),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::calc_alt_grid;
+
+    #[test]
+    fn test_prepare_pattern_serpentine() {
+        let raw = [
+            (2.0, 1.0),
+            (0.0, 0.0),
+            (f64::NAN, 1.0),
+            (1.0, 1.0),
+            (2.0, 0.0),
+            (0.0, 1.0),
+            (1.0, f64::INFINITY),
+            (1.0, 0.0001),
+        ];
+        let actual = prepare_pattern(&raw, ReorderMode::Serpentine { row_tol: 0.001 })
+            .iter()
+            .map(|c| (c.x, c.y))
+            .collect::<Vec<_>>();
+        let expected = calc_alt_grid(0.0, 3, 1.0, 0.0, 2, 1.0)
+            .map(|c| (c.x, c.y))
+            .collect::<Vec<_>>();
+        assert_eq!(actual[1], (1.0, 0.0001));
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(&expected) {
+            assert!((a.0 - e.0).abs() < 0.001 && (a.1 - e.1).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn test_prepare_pattern_nearest_neighbor() {
+        let raw = [(5.0, 0.0), (1.0, 0.0), (3.0, 0.0), (f64::NAN, f64::NAN)];
+        let actual = prepare_pattern(&raw, ReorderMode::NearestNeighbor)
+            .iter()
+            .map(|c| c.x)
+            .collect::<Vec<_>>();
+        assert_eq!(actual, vec![1.0, 3.0, 5.0]);
+
+        let kept = prepare_pattern(&raw, ReorderMode::Keep);
+        assert_eq!(
+            kept.iter().map(|c| c.x).collect::<Vec<_>>(),
+            [5.0, 1.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn test_optimize_order() {
+        let path_len = |start: (f64, f64), holes: &[Coord]| {
+            let mut pos = Coord::new(start.0, start.1);
+            let mut len = 0.0;
+            for c in holes {
+                len += pos.distance_to(c);
+                pos = c.clone();
+            }
+            len
+        };
+        // Two rows of three holes, listed alternating between the rows.
+        let holes = [
+            (0.0, 0.0),
+            (2.0, 1.0),
+            (1.0, 0.0),
+            (0.0, 1.0),
+            (2.0, 0.0),
+            (1.0, 1.0),
+        ]
+        .map(|(x, y)| Coord::new(x, y))
+        .to_vec();
+        let ordered = optimize_order(holes.clone(), (0.0, 0.0));
+        let actual = ordered.iter().map(|c| (c.x, c.y)).collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (2.0, 1.0),
+                (1.0, 1.0),
+                (0.0, 1.0)
+            ]
+        );
+        assert_eq!(path_len((0.0, 0.0), &ordered), 5.0);
+        assert!(path_len((0.0, 0.0), &ordered) < path_len((0.0, 0.0), &holes));
+
+        // Starting from the other corner walks the pattern the other way round.
+        let ordered = optimize_order(holes, (3.0, 1.0));
+        assert_eq!((ordered[0].x, ordered[0].y), (2.0, 1.0));
+    }
+}
@@ -0,0 +1,281 @@
+use crate::layout::Coord;
+#[cfg(feature = "std")]
+use crate::order::nearest_neighbor;
+#[cfg(feature = "std")]
+use crate::speeds::{feed_rate, rpm_from_sfm};
+
+/// The kind of hole operation a point is intended for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OpKind {
+    Spot,
+    Drill,
+    Tap,
+    Counterbore,
+}
+
+/// A point tagged with the operation to perform at it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpCoord {
+    pub coord: Coord,
+    pub op: OpKind,
+}
+
+/// Tags each point of a pattern with an operation.
+///
+/// This keeps the operation intent travelling with the geometry, so later stages can
+/// group and sequence points by operation.
+///
+/// # Parameters
+/// - coords: The points to tag.
+/// - op: Called with each point to choose its operation, e.g. `|_| OpKind::Drill`.
+///
+/// # Returns
+/// - An iterator of `OpCoord` values in the order of `coords`.
+///
+/// # Example
+/// ```rust
+/// use smithy::layout::calc_bolt_circle;
+/// use smithy::program::{map_ops, OpKind};
+/// let ops: Vec<_> = map_ops(calc_bolt_circle(2.0, 4, None, None, None, None), |_| OpKind::Tap).collect();
+/// assert!(ops.iter().all(|o| o.op == OpKind::Tap));
+/// ```
+pub fn map_ops(
+    coords: impl Iterator<Item = Coord>,
+    mut op: impl FnMut(&Coord) -> OpKind,
+) -> impl Iterator<Item = OpCoord> {
+    coords.map(move |coord| OpCoord {
+        op: op(&coord),
+        coord,
+    })
+}
+
+/// A cutting tool in the tool changer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tool {
+    /// Tool (pocket) number.
+    pub number: u32,
+    pub dia: f64,
+    pub op: OpKind,
+}
+
+/// Orders the holes of a multi-tool program to cut down on rapid travel.
+///
+/// Tools run in the order given, so no tool changes are added. Within each tool the
+/// holes are reordered with a nearest-neighbor tour that starts where the previous tool
+/// finished (the origin for the first tool).
+///
+/// # Parameters
+/// - ops: Each tool with the holes it cuts, in tool-change order.
+///
+/// # Returns
+/// - `Vec<(Tool, Vec<Coord>)>`: The same tools in the same order, with their holes
+///   reordered.
+///
+/// # Example
+/// ```rust
+/// use smithy::layout::Coord;
+/// use smithy::program::{sequence_program, OpKind, Tool};
+/// let drill = Tool { number: 2, dia: 0.201, op: OpKind::Drill };
+/// let holes = vec![Coord::new(3.0, 0.0), Coord::new(1.0, 0.0), Coord::new(2.0, 0.0)];
+/// let program = sequence_program(vec![(drill, holes)]);
+/// assert_eq!(program[0].1[0].x, 1.0);
+/// ```
+#[cfg(feature = "std")]
+pub fn sequence_program(ops: Vec<(Tool, Vec<Coord>)>) -> Vec<(Tool, Vec<Coord>)> {
+    let mut pos = (0.0, 0.0);
+    ops.into_iter()
+        .map(|(tool, holes)| {
+            let holes = nearest_neighbor(holes, pos);
+            if let Some(last) = holes.last() {
+                pos = (last.x, last.y);
+            }
+            (tool, holes)
+        })
+        .collect()
+}
+
+/// A single machining operation in a multi-tool program.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    pub tool: u32,
+    pub description: String,
+    pub rpm: f64,
+    pub feed: f64,
+}
+
+#[cfg(feature = "std")]
+impl Operation {
+    /// Creates an operation with explicit spindle speed and feed rate.
+    pub fn new(tool: u32, description: &str, rpm: f64, feed: f64) -> Self {
+        Operation {
+            tool,
+            description: description.to_string(),
+            rpm,
+            feed,
+        }
+    }
+
+    /// Creates an operation, deriving the RPM and feed from cutting data.
+    ///
+    /// # Parameters
+    /// - tool: Tool number in the machine's magazine or turret.
+    /// - description: Short tool description shown to the operator.
+    /// - sfm: Cutting speed in surface feet per minute.
+    /// - dia: Tool diameter in inches.
+    /// - flutes: Number of cutting edges on the tool.
+    /// - chip_load: Feed per tooth in inches.
+    pub fn from_cutting_data(
+        tool: u32,
+        description: &str,
+        sfm: f64,
+        dia: f64,
+        flutes: u32,
+        chip_load: f64,
+    ) -> Self {
+        let rpm = rpm_from_sfm(sfm, dia);
+        Operation::new(tool, description, rpm, feed_rate(rpm, flutes, chip_load))
+    }
+}
+
+/// Builds a setup sheet listing the tools used by a program.
+///
+/// Each operation becomes one row of an aligned text table with the tool number,
+/// description, spindle speed (whole RPM) and feed rate (two decimals), in program
+/// order, below a header row.
+///
+/// # Parameters
+/// - ops: The operations of the program, in the order they run.
+///
+/// # Returns
+/// - `String`: The formatted table, one line per row.
+///
+/// # Example
+/// ```rust
+/// use smithy::program::{setup_sheet, Operation};
+/// let sheet = setup_sheet(&[Operation::new(1, "#7 drill", 1900.0, 7.6)]);
+/// assert_eq!(sheet.lines().count(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn setup_sheet(ops: &[Operation]) -> String {
+    let desc_width = ops
+        .iter()
+        .map(|op| op.description.len())
+        .chain(Some("DESCRIPTION".len()))
+        .max()
+        .unwrap_or_default();
+
+    let mut sheet = format!(
+        "{:<5} {:<desc_width$} {:>6} {:>8}\n",
+        "TOOL", "DESCRIPTION", "RPM", "FEED"
+    );
+    for op in ops {
+        let tool = format!("T{}", op.tool);
+        sheet += &format!(
+            "{:<5} {:<desc_width$} {:>6.0} {:>8.2}\n",
+            tool, op.description, op.rpm, op.feed
+        );
+    }
+    sheet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::calc_alt_grid;
+
+    #[test]
+    fn test_map_ops() {
+        let kinds = [
+            OpKind::Spot,
+            OpKind::Drill,
+            OpKind::Tap,
+            OpKind::Counterbore,
+        ];
+        let tagged = map_ops(calc_alt_grid(0.0, 4, 1.0, 0.0, 1, 1.0), |c| {
+            kinds[c.x as usize]
+        })
+        .collect::<Vec<_>>();
+        let actual = tagged.iter().map(|o| o.op).collect::<Vec<_>>();
+        assert_eq!(actual, kinds);
+        assert_eq!(tagged[2].coord.x, 2.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_setup_sheet() {
+        let ops = [
+            Operation::from_cutting_data(3, "1/2 spot drill", 100.0, 0.5, 2, 0.002),
+            Operation::from_cutting_data(7, "#7 drill", 80.0, 0.201, 2, 0.003),
+            Operation::new(12, "1/4-20 tap", 300.0, 15.0),
+        ];
+        let sheet = setup_sheet(&ops);
+        let lines = sheet.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "TOOL  DESCRIPTION       RPM     FEED");
+        assert_eq!(lines[1], "T3    1/2 spot drill    764     3.06");
+        assert_eq!(lines[2], "T7    #7 drill         1520     9.12");
+        assert_eq!(lines[3], "T12   1/4-20 tap        300    15.00");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sequence_program() {
+        fn path_len(start: (f64, f64), holes: &[Coord]) -> f64 {
+            let mut pos = start;
+            let mut len = 0.0;
+            for c in holes {
+                len += ((c.x - pos.0).powi(2) + (c.y - pos.1).powi(2)).sqrt();
+                pos = (c.x, c.y);
+            }
+            len
+        }
+
+        let spot = Tool {
+            number: 1,
+            dia: 0.5,
+            op: OpKind::Spot,
+        };
+        let drill = Tool {
+            number: 2,
+            dia: 0.201,
+            op: OpKind::Drill,
+        };
+        let tap = Tool {
+            number: 3,
+            dia: 0.25,
+            op: OpKind::Tap,
+        };
+        // Holes listed in a scattered order, the same for every tool.
+        let holes = [
+            (4.0, 0.0),
+            (0.0, 1.0),
+            (4.0, 1.0),
+            (0.0, 0.0),
+            (2.0, 1.0),
+            (2.0, 0.0),
+        ]
+        .map(|(x, y)| Coord::new(x, y))
+        .to_vec();
+        let ops = vec![
+            (spot, holes.clone()),
+            (drill, holes.clone()),
+            (tap, holes[..3].to_vec()),
+        ];
+        let program = sequence_program(ops.clone());
+
+        let tools = program.iter().map(|(t, _)| t.number).collect::<Vec<_>>();
+        assert_eq!(tools, vec![1, 2, 3]);
+
+        let mut start = (0.0, 0.0);
+        for ((_, before), (_, after)) in ops.iter().zip(&program) {
+            assert_eq!(before.len(), after.len());
+            assert!(path_len(start, after) < path_len(start, before));
+            let last = after.last().unwrap();
+            start = (last.x, last.y);
+        }
+        // The drill picks up where the spot drill finished.
+        let spot_end = program[0].1.last().unwrap();
+        assert_eq!(&program[1].1[0], spot_end);
+    }
+}
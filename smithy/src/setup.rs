@@ -0,0 +1,266 @@
+use crate::float;
+
+/// The standard 81-piece inch gauge block set, largest first.
+///
+/// - 9 blocks from 0.1001" to 0.1009" in steps of 0.0001"
+/// - 49 blocks from 0.101" to 0.149" in steps of 0.001"
+/// - 19 blocks from 0.050" to 0.950" in steps of 0.050"
+/// - 4 blocks of 1.000", 2.000", 3.000" and 4.000"
+pub const GAUGE_BLOCKS_81: [f64; 81] = [
+    4.0, 3.0, 2.0, 1.0, 0.95, 0.9, 0.85, 0.8, 0.75, 0.7, 0.65, 0.6, 0.55, 0.5, 0.45, 0.4, 0.35,
+    0.3, 0.25, 0.2, 0.15, 0.149, 0.148, 0.147, 0.146, 0.145, 0.144, 0.143, 0.142, 0.141, 0.14,
+    0.139, 0.138, 0.137, 0.136, 0.135, 0.134, 0.133, 0.132, 0.131, 0.13, 0.129, 0.128, 0.127,
+    0.126, 0.125, 0.124, 0.123, 0.122, 0.121, 0.12, 0.119, 0.118, 0.117, 0.116, 0.115, 0.114,
+    0.113, 0.112, 0.111, 0.11, 0.109, 0.108, 0.107, 0.106, 0.105, 0.104, 0.103, 0.102, 0.101,
+    0.1009, 0.1008, 0.1007, 0.1006, 0.1005, 0.1004, 0.1003, 0.1002, 0.1001, 0.1, 0.05,
+];
+
+/// Calculates the gauge block stack height for setting a sine bar or sine plate to an
+/// angle.
+///
+/// ```markdown
+/// height = length × sin(angle)
+/// ```
+///
+/// # Parameters
+/// - bar_length: Distance between the sine bar's roll centers.
+/// - angle_deg: Desired angle in degrees.
+///
+/// # Returns
+/// - `f64`: Height of the gauge block stack under the raised roll, in the units of
+///   `bar_length`.
+///
+/// # Example
+/// ```rust
+/// use smithy::setup::sine_bar_height;
+/// assert!((sine_bar_height(5.0, 30.0) - 2.5).abs() < 1e-9);
+/// ```
+pub fn sine_bar_height(bar_length: f64, angle_deg: f64) -> f64 {
+    bar_length * float::sin(angle_deg.to_radians())
+}
+
+/// Calculates the angle of a sine bar set on a gauge block stack.
+///
+/// ```markdown
+/// angle = asin(height / length)
+/// ```
+///
+/// # Parameters
+/// - bar_length: Distance between the sine bar's roll centers.
+/// - height: Height of the gauge block stack, in the units of `bar_length`.
+///
+/// # Returns
+/// - `f64`: The angle in degrees, or `NaN` if `height` is greater than `bar_length`.
+///
+/// # Example
+/// ```rust
+/// use smithy::setup::sine_bar_angle;
+/// assert!((sine_bar_angle(5.0, 2.5) - 30.0).abs() < 1e-9);
+/// ```
+pub fn sine_bar_angle(bar_length: f64, height: f64) -> f64 {
+    let run = float::sqrt(bar_length * bar_length - height * height);
+    float::atan2(height, run).to_degrees()
+}
+
+/// Selects gauge blocks from a set that wring together to a target height.
+///
+/// Blocks are tried largest first and each block in the set is used at most once. When
+/// a choice leaves a remainder the rest of the set cannot make up, the next smaller block
+/// is tried instead, so the stack is the greedy one whenever the greedy choice works out.
+///
+/// `target` and `set` may be in any unit as long as they share it. Heights are compared
+/// to the nearest 0.00001 of that unit, which suits inch sets and is finer than any
+/// metric set. The search remembers each (block, remainder) pair that failed so no
+/// dead end is explored twice, and blocks taller than the target are never tried.
+///
+/// # Parameters
+/// - target: Desired stack height.
+/// - set: Available block sizes, in the unit of `target`, e.g. [`GAUGE_BLOCKS_81`]. Need
+///   not be sorted.
+///
+/// # Returns
+/// - `Option<Vec<f64>>`: The blocks in the stack, largest first, or `None` if the target
+///   is not finite, is shorter than the smallest block or taller than the whole set, or
+///   otherwise cannot be built from the set.
+///
+/// # Example
+/// ```rust
+/// use smithy::setup::{gauge_block_stack, GAUGE_BLOCKS_81};
+/// let stack = gauge_block_stack(2.5, &GAUGE_BLOCKS_81).unwrap();
+/// assert_eq!(stack, vec![2.0, 0.5]);
+/// ```
+#[cfg(feature = "std")]
+pub fn gauge_block_stack(target: f64, set: &[f64]) -> Option<Vec<f64>> {
+    use std::collections::HashSet;
+
+    /// Whether `blocks[i..]` can make `remaining`, pushing the blocks used onto `stack`.
+    fn search(
+        blocks: &[(u64, f64)],
+        tail_sums: &[u64],
+        i: usize,
+        remaining: u64,
+        dead: &mut HashSet<(usize, u64)>,
+        stack: &mut Vec<f64>,
+    ) -> bool {
+        if remaining == 0 {
+            return true;
+        }
+        if i == blocks.len() || tail_sums[i] < remaining || dead.contains(&(i, remaining)) {
+            return false;
+        }
+        let (units, size) = blocks[i];
+        if units <= remaining {
+            stack.push(size);
+            if search(blocks, tail_sums, i + 1, remaining - units, dead, stack) {
+                return true;
+            }
+            stack.pop();
+        }
+        if search(blocks, tail_sums, i + 1, remaining, dead, stack) {
+            return true;
+        }
+        dead.insert((i, remaining));
+        false
+    }
+
+    const UNITS_PER: f64 = 100_000.0;
+    let total: f64 = set.iter().filter(|b| b.is_finite() && **b > 0.0).sum();
+    let smallest = set
+        .iter()
+        .copied()
+        .filter(|b| b.is_finite() && *b > 0.0)
+        .reduce(f64::min)?;
+    // Past u64's range the 0.00001 grid can no longer be counted in whole units.
+    if !target.is_finite()
+        || target > total + 0.5 / UNITS_PER
+        || target < smallest - 0.5 / UNITS_PER
+        || target * UNITS_PER >= u64::MAX as f64
+    {
+        return None;
+    }
+
+    let to_units = |v: f64| (v * UNITS_PER).round() as u64;
+    let goal = to_units(target);
+    let mut blocks: Vec<(u64, f64)> = set
+        .iter()
+        .filter(|b| b.is_finite() && **b > 0.0)
+        .map(|&b| (to_units(b), b))
+        .filter(|b| b.0 > 0 && b.0 <= goal)
+        .collect();
+    blocks.sort_by_key(|b| std::cmp::Reverse(b.0));
+
+    // tail_sums[i] is the height of blocks[i..] stacked together.
+    let mut tail_sums = vec![0u64; blocks.len() + 1];
+    for i in (0..blocks.len()).rev() {
+        tail_sums[i] = tail_sums[i + 1].saturating_add(blocks[i].0);
+    }
+
+    let mut stack = Vec::new();
+    let mut dead = HashSet::new();
+    search(&blocks, &tail_sums, 0, goal, &mut dead, &mut stack).then_some(stack)
+}
+
+/// Calculates the measurement over (or between) two pins laid in the corners of a
+/// dovetail.
+///
+/// Each pin sits in the sharp corner formed by a flank and the face next to it, so its
+/// center lies on the corner's bisector and its far edge is `pin × (1 + cot(angle / 2)) / 2`
+/// beyond the corner. For a male dovetail the pins sit outside the flanks and the
+/// measurement is taken over them; for a female dovetail they sit inside and the
+/// measurement is taken between them:
+///
+/// ```markdown
+/// external: M = width + pin × (1 + cot(angle / 2))
+/// internal: M = width - pin × (1 + cot(angle / 2))
+/// ```
+///
+/// # Parameters
+/// - width: Width of the dovetail between the sharp corners the pins sit in.
+/// - angle_deg: Angle between each flank and the face the pins rest on, in degrees,
+///   e.g. 60 for a standard dovetail.
+/// - pin_dia: Diameter of the gauge pins.
+/// - internal: `true` for a female dovetail, `false` for a male dovetail.
+///
+/// # Returns
+/// - `f64`: The measurement over the pins for a male dovetail, or between them for a
+///   female dovetail, in the units of `width`.
+///
+/// # Example
+/// ```rust
+/// use smithy::setup::dovetail_measurement;
+/// let over = dovetail_measurement(1.0, 60.0, 0.25, false);
+/// assert!((over - 1.6830).abs() < 0.0001);
+/// ```
+pub fn dovetail_measurement(width: f64, angle_deg: f64, pin_dia: f64, internal: bool) -> f64 {
+    let offset = pin_dia * (1.0 + 1.0 / float::tan((angle_deg / 2.0).to_radians()));
+    if internal {
+        width - offset
+    } else {
+        width + offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math;
+
+    #[test]
+    fn test_sine_bar() {
+        assert_eq!(math::round(sine_bar_height(5.0, 30.0), 6), 2.5);
+        assert_eq!(math::round(sine_bar_height(10.0, 0.0), 6), 0.0);
+        assert_eq!(math::round(sine_bar_angle(5.0, 2.5), 6), 30.0);
+        assert_eq!(math::round(sine_bar_angle(5.0, 5.0), 6), 90.0);
+        assert!(sine_bar_angle(5.0, 6.0).is_nan());
+    }
+
+    #[test]
+    fn test_dovetail_measurement() {
+        // 60° dovetail, 1.000" between corners, 0.250" pins.
+        let over = dovetail_measurement(1.0, 60.0, 0.25, false);
+        assert_eq!(math::round(over, 4), 1.6830);
+        let between = dovetail_measurement(1.0, 60.0, 0.25, true);
+        assert_eq!(math::round(between, 4), 0.3170);
+
+        // At 90° the pins sit in square corners, one diameter each side.
+        assert_eq!(
+            math::round(dovetail_measurement(2.0, 90.0, 0.5, false), 6),
+            3.0
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_gauge_block_stack() {
+        let stack = gauge_block_stack(sine_bar_height(5.0, 30.0), &GAUGE_BLOCKS_81).unwrap();
+        assert_eq!(stack, vec![2.0, 0.5]);
+
+        // Largest-first alone would take 1.0 and 0.2 and strand 0.0345.
+        let stack = gauge_block_stack(1.2345, &GAUGE_BLOCKS_81).unwrap();
+        assert_eq!(stack, vec![1.0, 0.134, 0.1005]);
+
+        assert!(gauge_block_stack(0.0001, &GAUGE_BLOCKS_81).is_none());
+        assert!(gauge_block_stack(30.0, &GAUGE_BLOCKS_81).is_none());
+        assert!(gauge_block_stack(-1.0, &GAUGE_BLOCKS_81).is_none());
+        assert!(gauge_block_stack(f64::NAN, &GAUGE_BLOCKS_81).is_none());
+        assert!(gauge_block_stack(f64::INFINITY, &GAUGE_BLOCKS_81).is_none());
+        assert!(gauge_block_stack(f64::MAX, &[f64::MAX, 1.0]).is_none());
+        assert!(gauge_block_stack(1.0, &[]).is_none());
+
+        // Within the set's range but off its grid: no 0.00005 step exists below 0.1001.
+        assert!(gauge_block_stack(0.10015, &GAUGE_BLOCKS_81).is_none());
+
+        // Every block at once is the only way to reach the total.
+        let total: f64 = GAUGE_BLOCKS_81.iter().sum();
+        let stack = gauge_block_stack(total, &GAUGE_BLOCKS_81).unwrap();
+        assert_eq!(stack.len(), GAUGE_BLOCKS_81.len());
+
+        // A metric set works the same way: 40.125 mm, backing off from the 25 mm block.
+        let metric = [
+            1.005, 1.1, 1.12, 1.5, 2.0, 8.0, 10.0, 20.0, 25.0, 50.0, 100.0,
+        ];
+        let stack = gauge_block_stack(40.125, &metric).unwrap();
+        assert_eq!(stack, vec![20.0, 10.0, 8.0, 1.12, 1.005]);
+        assert!(gauge_block_stack(250.0, &metric).is_none());
+    }
+}
@@ -0,0 +1,832 @@
+use core::f64::consts::PI;
+
+use crate::float;
+use crate::layout::Coord;
+
+/// Workpiece material families used to select cutting data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Material {
+    Aluminum,
+    Brass,
+    CastIron,
+    MildSteel,
+    AlloySteel,
+    StainlessSteel,
+    Titanium,
+    Plastic,
+}
+
+/// Cutting tool materials.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolMaterial {
+    /// High-speed steel.
+    Hss,
+    Carbide,
+}
+
+/// Hole-making operations, each with its own speed and feed derates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HoleOp {
+    Drill,
+    Ream,
+    /// Rigid or floating tapping; the tap's threads per inch set the feed.
+    Tap {
+        tpi: u32,
+    },
+    Countersink,
+}
+
+/// Spindle speed and feed rate for a hole operation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HoleSpeeds {
+    pub rpm: f64,
+    /// Feed rate in inches per minute.
+    pub feed: f64,
+}
+
+/// Calculates the spindle speed for a surface speed in feet per minute.
+///
+/// ```markdown
+/// RPM = SFM × 12 / (π × D)
+/// ```
+///
+/// # Parameters
+/// - sfm: Cutting (surface) speed in surface feet per minute.
+/// - diameter_in: Diameter of the tool (or of the work when turning), in inches.
+///
+/// # Returns
+/// - `f64`: Spindle speed in revolutions per minute.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::rpm_from_sfm;
+/// assert_eq!(rpm_from_sfm(100.0, 0.5).round(), 764.0);
+/// ```
+pub fn rpm_from_sfm(sfm: f64, diameter_in: f64) -> f64 {
+    sfm * 12.0 / (PI * diameter_in)
+}
+
+/// Calculates the spindle speed for a surface speed in metres per minute.
+///
+/// ```markdown
+/// RPM = SMM × 1000 / (π × D)
+/// ```
+///
+/// # Parameters
+/// - smm: Cutting (surface) speed in metres per minute.
+/// - diameter_mm: Diameter of the tool (or of the work when turning), in mm.
+///
+/// # Returns
+/// - `f64`: Spindle speed in revolutions per minute.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::rpm_from_smm;
+/// assert_eq!(rpm_from_smm(30.0, 10.0).round(), 955.0);
+/// ```
+pub fn rpm_from_smm(smm: f64, diameter_mm: f64) -> f64 {
+    smm * 1000.0 / (PI * diameter_mm)
+}
+
+/// Calculates the surface speed in feet per minute for a spindle speed.
+///
+/// This is the inverse of `rpm_from_sfm`:
+///
+/// ```markdown
+/// SFM = RPM × π × D / 12
+/// ```
+///
+/// # Parameters
+/// - rpm: Spindle speed in revolutions per minute.
+/// - diameter_in: Diameter of the tool (or of the work when turning), in inches.
+///
+/// # Returns
+/// - `f64`: Surface speed in surface feet per minute.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::sfm_from_rpm;
+/// assert_eq!(sfm_from_rpm(764.0, 0.5).round(), 100.0);
+/// ```
+pub fn sfm_from_rpm(rpm: f64, diameter_in: f64) -> f64 {
+    rpm * PI * diameter_in / 12.0
+}
+
+/// Calculates the surface speed in metres per minute for a spindle speed.
+///
+/// This is the inverse of `rpm_from_smm`:
+///
+/// ```markdown
+/// SMM = RPM × π × D / 1000
+/// ```
+///
+/// # Parameters
+/// - rpm: Spindle speed in revolutions per minute.
+/// - diameter_mm: Diameter of the tool (or of the work when turning), in mm.
+///
+/// # Returns
+/// - `f64`: Surface speed in metres per minute.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::smm_from_rpm;
+/// assert_eq!(smm_from_rpm(1000.0, 10.0).round(), 31.0);
+/// ```
+pub fn smm_from_rpm(rpm: f64, diameter_mm: f64) -> f64 {
+    rpm * PI * diameter_mm / 1000.0
+}
+
+/// Calculates the feed rate from spindle speed, flute count and chip load.
+///
+/// ```markdown
+/// feed = RPM × flutes × chip load
+/// ```
+///
+/// # Parameters
+/// - rpm: Spindle speed in revolutions per minute.
+/// - flutes: Number of cutting edges on the tool.
+/// - chip_load: Feed per tooth, in inches (or mm).
+///
+/// # Returns
+/// - `f64`: Feed rate in inches (or mm) per minute.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::feed_rate;
+/// assert_eq!(feed_rate(1000.0, 4, 0.001), 4.0);
+/// ```
+pub fn feed_rate(rpm: f64, flutes: u32, chip_load: f64) -> f64 {
+    rpm * flutes as f64 * chip_load
+}
+
+/// Calculates the chip load produced by a feed rate, the inverse of `feed_rate`.
+///
+/// ```markdown
+/// chip load = feed / (RPM × flutes)
+/// ```
+///
+/// # Parameters
+/// - feed: Feed rate in inches (or mm) per minute.
+/// - rpm: Spindle speed in revolutions per minute.
+/// - flutes: Number of cutting edges on the tool.
+///
+/// # Returns
+/// - `f64`: Feed per tooth, in inches (or mm).
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::chip_load_from_feed;
+/// assert_eq!(chip_load_from_feed(4.0, 1000.0, 2), 0.002);
+/// ```
+pub fn chip_load_from_feed(feed: f64, rpm: f64, flutes: u32) -> f64 {
+    feed / (rpm * flutes as f64)
+}
+
+/// The speed and feed maxima of a machine.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MachineLimits {
+    /// Maximum spindle speed in RPM.
+    pub max_rpm: f64,
+    /// Maximum programmable cutting feed rate, in inches (or mm) per minute.
+    pub max_feed: f64,
+}
+
+/// A computed parameter that the machine cannot run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LimitWarning {
+    RpmExceeded { rpm: f64, max: f64 },
+    FeedExceeded { feed: f64, max: f64 },
+}
+
+/// Checks a spindle speed and feed rate against a machine's limits.
+///
+/// # Parameters
+/// - rpm: Spindle speed in RPM.
+/// - feed: Feed rate, in the same unit as `limits.max_feed`.
+/// - limits: The machine's maxima.
+///
+/// # Returns
+/// - `Vec<LimitWarning>`: One warning per exceeded limit; empty when the values can run.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::{check_machine_limits, MachineLimits};
+/// let limits = MachineLimits { max_rpm: 6000.0, max_feed: 100.0 };
+/// assert!(check_machine_limits(5000.0, 40.0, &limits).is_empty());
+/// ```
+#[cfg(feature = "std")]
+pub fn check_machine_limits(rpm: f64, feed: f64, limits: &MachineLimits) -> Vec<LimitWarning> {
+    let mut warnings = Vec::new();
+    if rpm > limits.max_rpm {
+        warnings.push(LimitWarning::RpmExceeded {
+            rpm,
+            max: limits.max_rpm,
+        });
+    }
+    if feed > limits.max_feed {
+        warnings.push(LimitWarning::FeedExceeded {
+            feed,
+            max: limits.max_feed,
+        });
+    }
+    warnings
+}
+
+/// Returns a starting-point surface speed for drilling and milling a material.
+///
+/// These are conservative handbook values for uncoated tools with flood coolant and
+/// should be adjusted for the rigidity of the setup.
+///
+/// # Parameters
+/// - material: The workpiece material.
+/// - tool: The cutting tool material.
+///
+/// # Returns
+/// - `f64`: Recommended cutting speed in surface feet per minute.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::{recommended_sfm, Material, ToolMaterial};
+/// assert_eq!(recommended_sfm(Material::MildSteel, ToolMaterial::Hss), 100.0);
+/// ```
+pub fn recommended_sfm(material: Material, tool: ToolMaterial) -> f64 {
+    match (material, tool) {
+        (Material::Aluminum, ToolMaterial::Hss) => 250.0,
+        (Material::Aluminum, ToolMaterial::Carbide) => 800.0,
+        (Material::Brass, ToolMaterial::Hss) => 200.0,
+        (Material::Brass, ToolMaterial::Carbide) => 600.0,
+        (Material::CastIron, ToolMaterial::Hss) => 80.0,
+        (Material::CastIron, ToolMaterial::Carbide) => 250.0,
+        (Material::MildSteel, ToolMaterial::Hss) => 100.0,
+        (Material::MildSteel, ToolMaterial::Carbide) => 350.0,
+        (Material::AlloySteel, ToolMaterial::Hss) => 60.0,
+        (Material::AlloySteel, ToolMaterial::Carbide) => 250.0,
+        (Material::StainlessSteel, ToolMaterial::Hss) => 50.0,
+        (Material::StainlessSteel, ToolMaterial::Carbide) => 200.0,
+        (Material::Titanium, ToolMaterial::Hss) => 30.0,
+        (Material::Titanium, ToolMaterial::Carbide) => 100.0,
+        (Material::Plastic, ToolMaterial::Hss) => 300.0,
+        (Material::Plastic, ToolMaterial::Carbide) => 800.0,
+    }
+}
+
+impl Material {
+    /// Brinell hardness the `recommended_sfm` values for this material assume.
+    ///
+    /// These are typical as-supplied grades: 6061-T6 aluminium, free-cutting brass,
+    /// class 30 grey iron, 1018 mild steel, annealed 4140, annealed 304 stainless and
+    /// annealed Ti-6Al-4V. Plastics are not rated on the Brinell scale and return None.
+    pub fn baseline_bhn(self) -> Option<f64> {
+        match self {
+            Material::Aluminum => Some(95.0),
+            Material::Brass => Some(100.0),
+            Material::CastIron => Some(200.0),
+            Material::MildSteel => Some(150.0),
+            Material::AlloySteel => Some(200.0),
+            Material::StainlessSteel => Some(170.0),
+            Material::Titanium => Some(330.0),
+            Material::Plastic => None,
+        }
+    }
+}
+
+/// Returns a multiplier for scaling surface speed to the hardness of the workpiece.
+///
+/// Harder material generates more heat at the cutting edge, so the speed falls off as
+/// hardness rises. The factor is 1.0 at the material's `baseline_bhn` and follows:
+///
+/// ```markdown
+/// factor = (baseline BHN / BHN)^0.8
+/// ```
+///
+/// # Parameters
+/// - material: The workpiece material, which sets the baseline hardness.
+/// - bhn: Brinell hardness number of the workpiece. Non-positive values, and materials
+///   without a baseline hardness, return 1.0.
+///
+/// # Returns
+/// - `f64`: Multiplier to apply to a recommended SFM.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::{hardness_sfm_factor, Material};
+/// assert_eq!(hardness_sfm_factor(Material::MildSteel, 150.0), 1.0);
+/// assert!(hardness_sfm_factor(Material::MildSteel, 300.0) < 0.6);
+/// ```
+pub fn hardness_sfm_factor(material: Material, bhn: f64) -> f64 {
+    match material.baseline_bhn() {
+        Some(baseline) if bhn > 0.0 => float::powf(baseline / bhn, 0.8),
+        _ => 1.0,
+    }
+}
+
+/// Returns the recommended surface speed adjusted for the measured workpiece hardness.
+///
+/// # Parameters
+/// - material: The workpiece material.
+/// - tool: The cutting tool material.
+/// - bhn: Brinell hardness number of the workpiece.
+///
+/// # Returns
+/// - `f64`: `recommended_sfm(material, tool) × hardness_sfm_factor(material, bhn)`.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::{recommended_sfm_for_hardness, Material, ToolMaterial};
+/// let sfm = recommended_sfm_for_hardness(Material::AlloySteel, ToolMaterial::Carbide, 300.0);
+/// assert!(sfm < 250.0);
+/// ```
+pub fn recommended_sfm_for_hardness(material: Material, tool: ToolMaterial, bhn: f64) -> f64 {
+    recommended_sfm(material, tool) * hardness_sfm_factor(material, bhn)
+}
+
+/// Calculates the spindle speed and feed rate for a hole operation.
+///
+/// The drilling speed comes from `recommended_sfm` and `rpm_from_sfm`, with a feed of
+/// `D / 65` per revolution. The other operations are derated from the drilling values:
+///
+/// - Ream: half the drilling RPM at twice the feed per revolution.
+/// - Tap: a quarter of the drilling RPM, fed at one pitch per revolution (`RPM / TPI`).
+/// - Countersink: half the drilling RPM at half the feed per revolution, to avoid chatter.
+///
+/// # Parameters
+/// - op: The hole operation.
+/// - dia: Diameter of the tool, in inches.
+/// - material: The workpiece material.
+/// - tool: The cutting tool material.
+///
+/// # Returns
+/// - `HoleSpeeds`: Spindle speed in RPM and feed rate in inches per minute.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::{hole_speeds, HoleOp, Material, ToolMaterial};
+/// let tap = hole_speeds(HoleOp::Tap { tpi: 20 }, 0.25, Material::MildSteel, ToolMaterial::Hss);
+/// assert_eq!(tap.feed, tap.rpm / 20.0);
+/// ```
+pub fn hole_speeds(op: HoleOp, dia: f64, material: Material, tool: ToolMaterial) -> HoleSpeeds {
+    let drill_rpm = rpm_from_sfm(recommended_sfm(material, tool), dia);
+    let drill_ipr = dia / 65.0;
+    let (rpm, ipr) = match op {
+        HoleOp::Drill => (drill_rpm, drill_ipr),
+        HoleOp::Ream => (drill_rpm * 0.5, drill_ipr * 2.0),
+        HoleOp::Tap { tpi } => {
+            let rpm = drill_rpm * 0.25;
+            return HoleSpeeds {
+                rpm,
+                feed: rpm / tpi as f64,
+            };
+        }
+        HoleOp::Countersink => (drill_rpm * 0.5, drill_ipr * 0.5),
+    };
+    HoleSpeeds {
+        rpm,
+        feed: feed_rate(rpm, 1, ipr),
+    }
+}
+
+/// Calculates the material removal rate of a milling cut.
+///
+/// ```markdown
+/// MRR = width of cut × depth of cut × feed
+/// ```
+///
+/// # Parameters
+/// - width_of_cut: Radial engagement (the tool diameter when slotting), in inches (or mm).
+/// - depth_of_cut: Axial depth of cut, in inches (or mm).
+/// - feed_rate: Feed rate in inches (or mm) per minute.
+///
+/// # Returns
+/// - `f64`: Material removal rate in cubic inches (or mm³) per minute.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::material_removal_rate;
+/// assert_eq!(material_removal_rate(0.5, 0.25, 10.0), 1.25);
+/// ```
+pub fn material_removal_rate(width_of_cut: f64, depth_of_cut: f64, feed_rate: f64) -> f64 {
+    width_of_cut * depth_of_cut * feed_rate
+}
+
+/// Estimates the spindle power a cut needs from its material removal rate.
+///
+/// ```markdown
+/// HP = MRR × unit power
+/// ```
+///
+/// # Parameters
+/// - mrr: Material removal rate in cubic inches per minute.
+/// - unit_power: Power per unit removal rate of the material in horsepower per cubic
+///   inch per minute, see `unit_power`.
+///
+/// # Returns
+/// - `f64`: Power at the cutter in horsepower. Divide by the drive efficiency (about
+///   0.8) for the motor power.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::{spindle_power_estimate, unit_power, Material};
+/// assert_eq!(spindle_power_estimate(2.0, unit_power(Material::MildSteel)), 2.0);
+/// ```
+pub fn spindle_power_estimate(mrr: f64, unit_power: f64) -> f64 {
+    mrr * unit_power
+}
+
+/// Returns the unit power of a material: the spindle power needed to remove one cubic
+/// inch of it per minute.
+///
+/// Handbook averages for sharp tools; dull tools can need up to 25% more.
+///
+/// # Parameters
+/// - material: The workpiece material.
+///
+/// # Returns
+/// - `f64`: Unit power in horsepower per cubic inch per minute.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::{unit_power, Material};
+/// assert_eq!(unit_power(Material::MildSteel), 1.0);
+/// ```
+pub fn unit_power(material: Material) -> f64 {
+    match material {
+        Material::Aluminum => 0.3,
+        Material::Brass => 0.8,
+        Material::CastIron => 0.9,
+        Material::MildSteel => 1.0,
+        Material::AlloySteel => 1.4,
+        Material::StainlessSteel => 1.5,
+        Material::Titanium => 1.2,
+        Material::Plastic => 0.1,
+    }
+}
+
+/// Joules per cubic millimetre in one horsepower-minute per cubic inch.
+const HP_MIN_PER_IN3: f64 = 745.699872 * 60.0 / 16387.064;
+
+/// Returns the specific cutting energy of a material: the energy needed to remove one
+/// cubic millimetre of it.
+///
+/// These are the `unit_power` handbook averages in SI units, e.g. 2.73 J/mm³ for mild
+/// steel. One J/mm³ is the same as one kW per cm³/s, or 1,000 N/mm² of specific cutting
+/// force (kc).
+///
+/// # Parameters
+/// - material: The workpiece material.
+///
+/// # Returns
+/// - `f64`: Specific cutting energy in J/mm³.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::{specific_energy, Material};
+/// assert!((specific_energy(Material::MildSteel) - 2.73).abs() < 0.01);
+/// ```
+pub fn specific_energy(material: Material) -> f64 {
+    unit_power(material) * HP_MIN_PER_IN3
+}
+
+/// Calculates the power a cut takes at the spindle from its material removal rate.
+///
+/// ```markdown
+/// P = MRR × u / 60,000
+/// ```
+///
+/// This is the metric form of `spindle_power_estimate`, which works in horsepower.
+///
+/// # Parameters
+/// - mrr: Material removal rate in mm³ per minute, see `material_removal_rate`.
+/// - specific_energy: Specific cutting energy (u) of the material in J/mm³, see
+///   `specific_energy`.
+///
+/// # Returns
+/// - `f64`: Power at the cutter in kW. Divide by the drive efficiency (about 0.8) for
+///   the motor power.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::cutting_power;
+/// assert_eq!(cutting_power(30_000.0, 2.0), 1.0);
+/// ```
+pub fn cutting_power(mrr: f64, specific_energy: f64) -> f64 {
+    mrr * specific_energy / 60_000.0
+}
+
+/// Calculates the torque at the spindle for a given power and speed.
+///
+/// ```markdown
+/// T = P × 60,000 / (2π × n) ≈ 9,549 × P / n
+/// ```
+///
+/// Compare the result with the machine's torque curve: small machines often have the
+/// power for a cut at high speed but stall at the low speed a large cutter needs.
+///
+/// # Parameters
+/// - power: Power at the spindle in kW, see `cutting_power`.
+/// - rpm: Spindle speed (n) in revolutions per minute.
+///
+/// # Returns
+/// - `f64`: Spindle torque in N·m. Multiply by 0.7376 for lbf·ft.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::spindle_torque;
+/// assert!((spindle_torque(1.0, 1000.0) - 9.549).abs() < 0.001);
+/// ```
+pub fn spindle_torque(power: f64, rpm: f64) -> f64 {
+    power * 60_000.0 / (2.0 * PI * rpm)
+}
+
+/// Calculates a feed for each step of a cut so the spindle power stays under a limit.
+///
+/// Each step is treated as a full-width cut of the tool diameter at the given axial
+/// depth, so the removal rate and power are:
+///
+/// ```markdown
+/// MRR   = depth × tool_dia × feed
+/// power = MRR × unit power
+/// feed  = max_power / (unit power × depth × tool_dia)
+/// ```
+///
+/// # Parameters
+/// - depth_profile: Axial depth of cut for each step, in inches.
+/// - max_power: Spindle power available for cutting, in horsepower.
+/// - material: The workpiece material, which sets the unit power.
+/// - tool_dia: Diameter of the tool, in inches.
+///
+/// # Returns
+/// - `Vec<f64>`: Feed rate in inches per minute for each step. Steps that don't engage the
+///   tool (depth of zero or less) are not power limited and return `f64::INFINITY`, so
+///   clamp the result to the machine's maximum feed.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::{power_limited_feed, Material};
+/// let feeds = power_limited_feed(&[0.1, 0.2], 0.5, Material::Aluminum, 0.5);
+/// assert!(feeds[1] < feeds[0]);
+/// ```
+#[cfg(feature = "std")]
+pub fn power_limited_feed(
+    depth_profile: &[f64],
+    max_power: f64,
+    material: Material,
+    tool_dia: f64,
+) -> Vec<f64> {
+    let hp_per_ipm = |depth: f64| {
+        spindle_power_estimate(
+            material_removal_rate(tool_dia, depth, 1.0),
+            unit_power(material),
+        )
+    };
+    depth_profile
+        .iter()
+        .map(|&depth| {
+            if depth <= 0.0 {
+                f64::INFINITY
+            } else {
+                max_power / hp_per_ipm(depth)
+            }
+        })
+        .collect()
+}
+
+/// Splits the feed rate of a linear move into its per-axis components.
+///
+/// The controller moves along the straight line from `from` to `to` at the vector
+/// feed `feed`, so each axis travels at `feed × |Δaxis| / length`. Comparing these with
+/// the machine's per-axis limits shows whether a diagonal move overspeeds an axis.
+///
+/// Z is only included when both points have a z value; otherwise the move is treated
+/// as planar.
+///
+/// # Parameters
+/// - from: Start of the move.
+/// - to: End of the move.
+/// - feed: Programmed (vector) feed rate.
+///
+/// # Returns
+/// - `(f64, f64, f64)`: The X, Y and Z axis feed rates, always non-negative. All zero
+///   for a zero-length move.
+///
+/// # Example
+/// ```rust
+/// use smithy::layout::Coord;
+/// use smithy::speeds::axis_feeds;
+/// let a = Coord { x: 0.0, y: 0.0, z: None, angle: None };
+/// let b = Coord { x: 3.0, y: 4.0, z: None, angle: None };
+/// assert_eq!(axis_feeds(&a, &b, 10.0), (6.0, 8.0, 0.0));
+/// ```
+pub fn axis_feeds(from: &Coord, to: &Coord, feed: f64) -> (f64, f64, f64) {
+    let dx = to.x - from.x;
+    let dy = to.y - from.y;
+    let dz = match (from.z, to.z) {
+        (Some(a), Some(b)) => b - a,
+        _ => 0.0,
+    };
+    let len = float::sqrt(dx * dx + dy * dy + dz * dz);
+    if len == 0.0 {
+        return (0.0, 0.0, 0.0);
+    }
+    (
+        feed * dx.abs() / len,
+        feed * dy.abs() / len,
+        feed * dz.abs() / len,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math;
+
+    #[test]
+    fn test_sfm_from_rpm() {
+        assert_eq!(math::round(sfm_from_rpm(764.0, 0.5), 2), 100.01);
+        let rpm = 1234.5;
+        assert_eq!(
+            math::round(rpm_from_sfm(sfm_from_rpm(rpm, 0.375), 0.375), 9),
+            rpm
+        );
+        assert_eq!(math::round(smm_from_rpm(2000.0, 12.0), 2), 75.4);
+    }
+
+    #[test]
+    fn test_rpm_from_sfm() {
+        assert_eq!(math::round(rpm_from_sfm(100.0, 0.5), 1), 763.9);
+        assert_eq!(math::round(rpm_from_sfm(300.0, 0.25), 1), 4583.7);
+        assert_eq!(math::round(rpm_from_smm(100.0, 12.0), 1), 2652.6);
+        // Round trip through the inverse.
+        let rpm = rpm_from_sfm(350.0, 0.375);
+        assert_eq!(math::round(sfm_from_rpm(rpm, 0.375), 9), 350.0);
+        assert_eq!(
+            math::round(smm_from_rpm(rpm_from_smm(80.0, 6.0), 6.0), 9),
+            80.0
+        );
+    }
+
+    #[test]
+    fn test_feed_rate() {
+        let feed = feed_rate(764.0, 2, 0.002);
+        assert_eq!(math::round(feed, 3), 3.056);
+        assert_eq!(math::round(chip_load_from_feed(feed, 764.0, 2), 9), 0.002);
+        assert_eq!(math::round(chip_load_from_feed(12.0, 3000.0, 4), 9), 0.001);
+    }
+
+    #[test]
+    fn test_hole_speeds() {
+        let m = Material::MildSteel;
+        let t = ToolMaterial::Hss;
+        let drill = hole_speeds(HoleOp::Drill, 0.25, m, t);
+        assert_eq!(math::round(drill.rpm, 1), 1527.9);
+        assert_eq!(math::round(drill.feed, 2), 5.88);
+
+        let ream = hole_speeds(HoleOp::Ream, 0.25, m, t);
+        assert!(ream.rpm < drill.rpm);
+
+        let tap = hole_speeds(HoleOp::Tap { tpi: 20 }, 0.25, m, t);
+        assert_eq!(tap.feed, tap.rpm / 20.0);
+        assert!(tap.rpm < ream.rpm);
+
+        let csk = hole_speeds(HoleOp::Countersink, 0.25, m, t);
+        assert!(csk.feed < drill.feed);
+    }
+
+    #[test]
+    fn test_recommended_sfm() {
+        for m in [Material::Aluminum, Material::MildSteel, Material::Titanium] {
+            assert!(
+                recommended_sfm(m, ToolMaterial::Carbide) > recommended_sfm(m, ToolMaterial::Hss)
+            );
+        }
+    }
+
+    #[test]
+    fn test_hardness_sfm_factor() {
+        let steel = Material::MildSteel;
+        assert_eq!(hardness_sfm_factor(steel, 150.0), 1.0);
+        assert!(hardness_sfm_factor(steel, 300.0) < hardness_sfm_factor(steel, 200.0));
+        assert!(hardness_sfm_factor(steel, 100.0) > 1.0);
+        assert_eq!(math::round(hardness_sfm_factor(steel, 300.0), 3), 0.574);
+
+        // Each material is scaled from its own typical hardness.
+        for m in [
+            Material::Aluminum,
+            Material::Brass,
+            Material::CastIron,
+            Material::MildSteel,
+            Material::AlloySteel,
+            Material::StainlessSteel,
+            Material::Titanium,
+        ] {
+            let bhn = m.baseline_bhn().unwrap();
+            assert_eq!(hardness_sfm_factor(m, bhn), 1.0);
+            assert_eq!(
+                recommended_sfm_for_hardness(m, ToolMaterial::Hss, bhn),
+                recommended_sfm(m, ToolMaterial::Hss)
+            );
+        }
+        assert!(hardness_sfm_factor(Material::Aluminum, 95.0) < hardness_sfm_factor(steel, 95.0));
+        assert_eq!(hardness_sfm_factor(Material::Plastic, 30.0), 1.0);
+    }
+
+    #[test]
+    fn test_material_removal_rate() {
+        // Slotting 1/2" deep with a 1/2" end mill at 8 IPM.
+        let mrr = material_removal_rate(0.5, 0.5, 8.0);
+        assert_eq!(mrr, 2.0);
+        assert_eq!(
+            spindle_power_estimate(mrr, unit_power(Material::Aluminum)),
+            0.6
+        );
+        assert_eq!(
+            spindle_power_estimate(mrr, unit_power(Material::AlloySteel)),
+            2.8
+        );
+    }
+
+    #[test]
+    fn test_cutting_power() {
+        // 1 HP per cubic inch per minute.
+        assert_eq!(math::round(specific_energy(Material::MildSteel), 4), 2.7303);
+        assert!(specific_energy(Material::Aluminum) < specific_energy(Material::Titanium));
+
+        // The metric and imperial power estimates agree: 2 in³/min of mild steel is 2 HP.
+        let mrr = material_removal_rate(0.5, 0.5, 8.0) * 16387.064;
+        let kw = cutting_power(mrr, specific_energy(Material::MildSteel));
+        assert_eq!(math::round(kw / 0.745699872, 9), 2.0);
+    }
+
+    #[test]
+    fn test_spindle_torque() {
+        // 7.5 kW at 1,500 RPM.
+        assert_eq!(math::round(spindle_torque(7.5, 1500.0), 2), 47.75);
+        // Halving the speed at the same power doubles the torque.
+        assert_eq!(
+            spindle_torque(7.5, 750.0),
+            2.0 * spindle_torque(7.5, 1500.0)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_power_limited_feed() {
+        let feeds = power_limited_feed(&[0.05, 0.1, 0.25, 0.0], 1.0, Material::MildSteel, 0.5);
+        assert_eq!(feeds[0], 40.0);
+        assert_eq!(feeds[1], 20.0);
+        assert!(feeds[2] < feeds[1]);
+        assert_eq!(feeds[3], f64::INFINITY);
+
+        // Estimated power at the returned feed is at the limit.
+        let power = 0.25 * 0.5 * feeds[2] * unit_power(Material::MildSteel);
+        assert_eq!(math::round(power, 9), 1.0);
+    }
+
+    #[test]
+    fn test_axis_feeds() {
+        let from = Coord {
+            x: 1.0,
+            y: 1.0,
+            z: Some(0.0),
+            angle: None,
+        };
+        let to = Coord {
+            x: -1.0,
+            y: 3.0,
+            z: Some(0.0),
+            angle: None,
+        };
+        let (fx, fy, fz) = axis_feeds(&from, &to, 20.0);
+        let expected = 20.0 * 45f64.to_radians().cos();
+        assert_eq!(math::round(fx, 9), math::round(expected, 9));
+        assert_eq!(math::round(fy, 9), math::round(expected, 9));
+        assert_eq!(fz, 0.0);
+
+        let plunge = Coord {
+            z: Some(-0.5),
+            ..from.clone()
+        };
+        assert_eq!(axis_feeds(&from, &plunge, 5.0), (0.0, 0.0, 5.0));
+        assert_eq!(axis_feeds(&from, &from, 5.0), (0.0, 0.0, 0.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_check_machine_limits() {
+        let limits = MachineLimits {
+            max_rpm: 3000.0,
+            max_feed: 60.0,
+        };
+        assert!(check_machine_limits(3000.0, 60.0, &limits).is_empty());
+
+        // A small carbide drill in aluminum wants more than a hobby spindle can give.
+        let rpm = rpm_from_sfm(
+            recommended_sfm(Material::Aluminum, ToolMaterial::Carbide),
+            0.125,
+        );
+        assert_eq!(
+            check_machine_limits(rpm, 20.0, &limits),
+            vec![LimitWarning::RpmExceeded { rpm, max: 3000.0 }]
+        );
+        assert_eq!(check_machine_limits(4000.0, 75.0, &limits).len(), 2);
+    }
+}
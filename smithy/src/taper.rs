@@ -0,0 +1,176 @@
+use crate::float;
+
+/// Calculates the included angle of a taper from its diameters and length.
+///
+/// ```markdown
+/// angle = 2 × atan((large - small) / (2 × length))
+/// ```
+///
+/// # Parameters
+/// - large: Diameter at the large end of the taper.
+/// - small: Diameter at the small end of the taper.
+/// - length: Axial length of the taper, in the units of the diameters.
+///
+/// # Returns
+/// - `f64`: The included angle in degrees.
+///
+/// # Example
+/// ```rust
+/// use smithy::taper::taper_angle_from_diameters;
+/// let angle = taper_angle_from_diameters(1.0, 0.5, 2.0);
+/// assert!((angle - 14.25).abs() < 0.01);
+/// ```
+pub fn taper_angle_from_diameters(large: f64, small: f64, length: f64) -> f64 {
+    2.0 * float::atan2(large - small, 2.0 * length).to_degrees()
+}
+
+/// Calculates the taper per foot (TPF) from the diameters and length of a taper.
+///
+/// ```markdown
+/// TPF = (large - small) × 12 / length
+/// ```
+///
+/// # Parameters
+/// - large: Diameter at the large end of the taper, in inches.
+/// - small: Diameter at the small end of the taper, in inches.
+/// - length: Axial length of the taper, in inches.
+///
+/// # Returns
+/// - `f64`: The change in diameter per foot of length, in inches. Divide by 12 for the
+///   taper per inch.
+///
+/// # Example
+/// ```rust
+/// use smithy::taper::tpf_from_diameters;
+/// assert_eq!(tpf_from_diameters(1.0, 0.75, 6.0), 0.5);
+/// ```
+pub fn tpf_from_diameters(large: f64, small: f64, length: f64) -> f64 {
+    (large - small) * 12.0 / length
+}
+
+/// Calculates the taper per foot (TPF) of a taper with the given included angle.
+///
+/// ```markdown
+/// TPF = 24 × tan(angle / 2)
+/// ```
+///
+/// # Parameters
+/// - angle: Included angle of the taper, in degrees.
+///
+/// # Returns
+/// - `f64`: The change in diameter per foot of length, in inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::taper::tpf_from_angle;
+/// assert!((tpf_from_angle(2.8614) - 0.5994).abs() < 0.0001);
+/// ```
+pub fn tpf_from_angle(angle: f64) -> f64 {
+    24.0 * float::tan((angle / 2.0).to_radians())
+}
+
+/// Calculates the included angle of a taper from its taper per foot (TPF).
+///
+/// ```markdown
+/// angle = 2 × atan(TPF / 24)
+/// ```
+///
+/// # Parameters
+/// - tpf: Taper per foot, in inches.
+///
+/// # Returns
+/// - `f64`: The included angle in degrees. Set the compound to half of this.
+///
+/// # Example
+/// ```rust
+/// use smithy::taper::angle_from_tpf;
+/// assert!((angle_from_tpf(0.75) - 3.5798).abs() < 0.0001);
+/// ```
+pub fn angle_from_tpf(tpf: f64) -> f64 {
+    2.0 * float::atan2(tpf, 24.0).to_degrees()
+}
+
+/// Calculates the small diameter of a taper from the large diameter, taper per foot and
+/// length.
+///
+/// ```markdown
+/// small = large - TPF × length / 12
+/// ```
+///
+/// # Parameters
+/// - large: Diameter at the large end of the taper, in inches.
+/// - tpf: Taper per foot, in inches.
+/// - length: Axial length of the taper, in inches.
+///
+/// # Returns
+/// - `f64`: Diameter at the small end of the taper, in inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::taper::small_diameter;
+/// assert_eq!(small_diameter(1.0, 0.5, 6.0), 0.75);
+/// ```
+pub fn small_diameter(large: f64, tpf: f64, length: f64) -> f64 {
+    large - tpf * length / 12.0
+}
+
+/// Calculates the tailstock setover for turning a taper between centers.
+///
+/// The offset is based on the overall length of the work between centers, not the
+/// length of the tapered section, since the whole workpiece is tilted:
+///
+/// ```markdown
+/// offset = TPF × workpiece length / 24
+/// ```
+///
+/// # Parameters
+/// - tpf: Taper per foot, in inches.
+/// - workpiece_length: Overall length of the workpiece between centers, in inches.
+///
+/// # Returns
+/// - `f64`: Distance to offset the tailstock from center, in inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::taper::tailstock_offset;
+/// assert_eq!(tailstock_offset(0.5, 6.0), 0.125);
+/// ```
+pub fn tailstock_offset(tpf: f64, workpiece_length: f64) -> f64 {
+    tpf * workpiece_length / 24.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math;
+
+    #[test]
+    fn test_taper_conversions() {
+        // Morse #2: 0.700" gauge diameter, 0.5994" per foot.
+        let angle = angle_from_tpf(0.5994);
+        assert_eq!(math::round(angle, 4), 2.8613);
+        assert_eq!(math::round(tpf_from_angle(angle), 6), 0.5994);
+
+        let small = small_diameter(0.700, 0.5994, 2.5);
+        assert_eq!(math::round(small, 4), 0.5751);
+        assert_eq!(
+            math::round(tpf_from_diameters(0.700, small, 2.5), 6),
+            0.5994
+        );
+        assert_eq!(
+            math::round(taper_angle_from_diameters(0.700, small, 2.5), 4),
+            2.8613
+        );
+
+        // A parallel part has no taper.
+        assert_eq!(taper_angle_from_diameters(1.0, 1.0, 3.0), 0.0);
+    }
+
+    #[test]
+    fn test_tailstock_offset() {
+        assert_eq!(tailstock_offset(0.5, 6.0), 0.125);
+        // A 1" to 0.9" taper on a 4" section of a 10" part: TPF 0.3, offset 0.125.
+        let tpf = tpf_from_diameters(1.0, 0.9, 4.0);
+        assert_eq!(math::round(tailstock_offset(tpf, 10.0), 6), 0.125);
+    }
+}
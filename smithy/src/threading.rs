@@ -1,15 +1,472 @@
-use crate::util::truncate_float;
+use core::f64::consts::PI;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::drilling::DrillSize;
+#[cfg(feature = "std")]
+use crate::drilling::{clearance_drill, closest_drill, nearest_drill, ClearanceFit, DrillSet};
+use crate::float;
+use crate::speeds::Material;
+
+/// Height of the fundamental (sharp V) triangle of a 60° thread per unit of pitch,
+/// `H = √3 / 2 × P`.
+const SQRT3_OVER_2: f64 = 0.866_025_403_784_438_6;
+
+/// Major diameter less basic pitch diameter per unit of pitch: twice the external
+/// addendum of `3H/8`, or `0.649519 × P`.
+const PITCH_DIA_FACTOR: f64 = 2.0 * (3.0 / 8.0) * SQRT3_OVER_2;
+
+/// Major diameter less basic minor diameter per unit of pitch: twice the basic thread
+/// height of `5H/8`, or `1.082532 × P`. This is the minor diameter of a nut and the
+/// 100% engagement depth for tap drills.
+const BASIC_MINOR_FACTOR: f64 = 2.0 * (5.0 / 8.0) * SQRT3_OVER_2;
+
+/// Maximum major diameter less maximum minor diameter of a UN (flat root) external
+/// thread per unit of pitch. The `P/8` root flat sits `3H/4` below the major diameter,
+/// so this is `1.5 × H`, or `1.299038 × P`.
+const UN_MINOR_FACTOR: f64 = 2.0 * (3.0 / 4.0) * SQRT3_OVER_2;
+
+/// Maximum major diameter less maximum minor diameter of a UNR (rounded root) external
+/// thread per unit of pitch. The sharp V root at `7H/8` is rounded off by the maximum
+/// root radius, which lifts it by `H/6`, giving `2 × (7H/8 - H/6)`, or `1.226869 × P`.
+const UNR_MINOR_FACTOR: f64 = 2.0 * (7.0 / 8.0 - 1.0 / 6.0) * SQRT3_OVER_2;
+
 /// Represents the different thread classes (1A, 2A, and 3A) for external threads.
 ///
 /// - A1: Loose fit (with allowance).
 /// - A2: General fit (with allowance).
 /// - A3 Precision fit (no allowance).
+///
+/// Classes parse from and print as their usual designation, e.g. `"2A"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ThreadClass {
     A1,
     A2,
     A3,
 }
 
+impl fmt::Display for ThreadClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ThreadClass::A1 => "1A",
+            ThreadClass::A2 => "2A",
+            ThreadClass::A3 => "3A",
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for ThreadClass {
+    type Err = ParseThreadClassError;
+
+    /// Parses `"1A"`, `"2A"` or `"3A"`, ignoring case and surrounding whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1A" | "1a" => Ok(ThreadClass::A1),
+            "2A" | "2a" => Ok(ThreadClass::A2),
+            "3A" | "3a" => Ok(ThreadClass::A3),
+            _ => Err(ParseThreadClassError),
+        }
+    }
+}
+
+/// The error returned when a string is not a valid thread class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseThreadClassError;
+
+impl fmt::Display for ParseThreadClassError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid thread class, expected 1A, 2A or 3A")
+    }
+}
+
+impl core::error::Error for ParseThreadClassError {}
+
+/// Represents the thread classes (1B, 2B, and 3B) for internal threads.
+///
+/// - B1: Loose fit.
+/// - B2: General fit.
+/// - B3: Precision fit.
+///
+/// Internal threads have no allowance; the classes differ only in their tolerances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadClassInternal {
+    B1,
+    B2,
+    B3,
+}
+
+/// Represents the root form of an external thread.
+///
+/// - Un: Flat root. The minor diameter is given for a root flat of P/8.
+/// - Unr: Rounded root (UNR series) with a radius between 0.108253 × P and
+///   0.144338 × P. The minor diameter is given at the maximum radius, which raises it
+///   above the flat-root minor and reduces the stress concentration at the root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootForm {
+    Un,
+    Unr,
+}
+
+/// Unified inch thread series.
+///
+/// - Unc: Coarse.
+/// - Unf: Fine.
+/// - Unef: Extra fine.
+/// - Un: Constant pitch series (e.g. 8-UN, 12-UN) and special (UNS) threads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnSeries {
+    Unc,
+    Unf,
+    Unef,
+    Un,
+}
+
+/// The class of a Unified thread, which also tells whether it is external or internal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnifiedClass {
+    External(ThreadClass),
+    Internal(ThreadClassInternal),
+}
+
+/// A parsed thread designation, see `parse_thread_spec`.
+///
+/// The fields are ready to pass to the calculations, e.g. `calc_uts_extern_thread` or
+/// `calc_iso_extern_thread`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThreadSpec {
+    /// A Unified inch thread. The major diameter is in inches.
+    Unified {
+        major_dia: f64,
+        tpi: u32,
+        series: Option<UnSeries>,
+        root: RootForm,
+        class: Option<UnifiedClass>,
+    },
+    /// An ISO metric thread. The major diameter and pitch are in mm.
+    Metric {
+        major_dia: f64,
+        pitch: f64,
+        class: Option<IsoToleranceClass>,
+    },
+}
+
+/// The error returned when a string is not a recognized thread designation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseThreadSpecError;
+
+impl fmt::Display for ParseThreadSpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid thread designation, expected e.g. 1/2-13 UNC 2A or M10x1.5-6g")
+    }
+}
+
+impl core::error::Error for ParseThreadSpecError {}
+
+/// Parses an inch diameter: a decimal, a fraction, a whole number and fraction joined by
+/// `-`, or a number size (`#0` to `#12`, with or without the `#`), which is
+/// `0.060 + 0.013 × N` inches.
+fn parse_inch_dia(s: &str) -> Option<f64> {
+    let fraction = |f: &str| {
+        let (num, den) = f.split_once('/')?;
+        let den = den.parse::<f64>().ok().filter(|&d| d > 0.0)?;
+        Some(num.parse::<f64>().ok()? / den)
+    };
+    let number_size = |n: u32| (n <= 12).then_some((60 + 13 * n) as f64 / 1000.0);
+    if let Some(n) = s.strip_prefix('#') {
+        return number_size(n.parse().ok()?);
+    }
+    let (s, inch_mark) = match s.strip_suffix('"') {
+        Some(s) => (s, true),
+        None => (s, false),
+    };
+    let dia = match s.split_once('-') {
+        Some((whole, frac)) => whole.parse::<u32>().ok()? as f64 + fraction(frac)?,
+        None if s.contains('/') => fraction(s)?,
+        None => match s.parse::<u32>() {
+            // Bare 0 and 2 to 12 are number sizes; larger inch sizes need the inch mark.
+            Ok(n @ (0 | 2..=12)) if !inch_mark => number_size(n)?,
+            _ => s.parse().ok()?,
+        },
+    };
+    (dia.is_finite() && dia > 0.0).then_some(dia)
+}
+
+/// Thread series designations and the series and root form they stand for.
+const UN_SERIES: [(&str, (UnSeries, RootForm)); 9] = [
+    ("UNC", (UnSeries::Unc, RootForm::Un)),
+    ("UNF", (UnSeries::Unf, RootForm::Un)),
+    ("UNEF", (UnSeries::Unef, RootForm::Un)),
+    ("UN", (UnSeries::Un, RootForm::Un)),
+    ("UNS", (UnSeries::Un, RootForm::Un)),
+    ("UNRC", (UnSeries::Unc, RootForm::Unr)),
+    ("UNRF", (UnSeries::Unf, RootForm::Unr)),
+    ("UNREF", (UnSeries::Unef, RootForm::Unr)),
+    ("UNR", (UnSeries::Un, RootForm::Unr)),
+];
+
+/// Unified thread class designations.
+const UN_CLASSES: [(&str, UnifiedClass); 6] = [
+    ("1A", UnifiedClass::External(ThreadClass::A1)),
+    ("2A", UnifiedClass::External(ThreadClass::A2)),
+    ("3A", UnifiedClass::External(ThreadClass::A3)),
+    ("1B", UnifiedClass::Internal(ThreadClassInternal::B1)),
+    ("2B", UnifiedClass::Internal(ThreadClassInternal::B2)),
+    ("3B", UnifiedClass::Internal(ThreadClassInternal::B3)),
+];
+
+/// Finds a designation in a table, ignoring case.
+fn lookup<T: Copy>(table: &[(&str, T)], name: &str) -> Option<T> {
+    table
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|&(_, v)| v)
+}
+
+fn parse_unified(s: &str) -> Option<ThreadSpec> {
+    let mut words = s.split_whitespace();
+    let first = words.next()?;
+    // A mixed fraction has a `-` of its own (1-1/2), so the TPI follows the first `-`
+    // after the fraction bar, if there is one.
+    let dia_end = match first.find('/') {
+        Some(bar) => bar + first[bar..].find('-')?,
+        None => first.find('-')?,
+    };
+    let major_dia = parse_inch_dia(&first[..dia_end])?;
+    let mut rest = first[dia_end + 1..].split('-');
+    // The series may be written straight after the TPI, e.g. "20UNC".
+    let tpi = rest.next()?;
+    let (tpi, suffix) = tpi.split_at(tpi.find(|c: char| !c.is_ascii_digit()).unwrap_or(tpi.len()));
+    let tpi = tpi.parse::<u32>().ok().filter(|&t| t > 0)?;
+
+    let (mut series, mut root, mut class) = (None, RootForm::Un, None);
+    // The series comes before the class, e.g. "UNC 2A" or "UNC-2A".
+    let tokens = core::iter::once(suffix)
+        .chain(rest)
+        .chain(words.flat_map(|w| w.split('-')));
+    for token in tokens.filter(|t| !t.is_empty()) {
+        if let (None, None, Some((s, r))) = (series, class, lookup(&UN_SERIES, token)) {
+            series = Some(s);
+            root = r;
+        } else if let (None, Some(c)) = (class, lookup(&UN_CLASSES, token)) {
+            class = Some(c);
+        } else {
+            return None;
+        }
+    }
+    Some(ThreadSpec::Unified {
+        major_dia,
+        tpi,
+        series,
+        root,
+        class,
+    })
+}
+
+fn parse_metric(s: &str) -> Option<ThreadSpec> {
+    let rest = s.strip_prefix(['M', 'm'])?;
+    let (size, class) = match rest.split_once('-') {
+        Some((size, class)) => (size, Some(class.trim())),
+        None => (rest, None),
+    };
+    let (dia, pitch) = size.split_once(['x', 'X', '×'])?;
+    let major_dia = dia.trim().parse::<f64>().ok().filter(|&d| d > 0.0)?;
+    let pitch = pitch.trim().parse::<f64>().ok().filter(|&p| p > 0.0)?;
+    let class = match class {
+        None => None,
+        Some(c) => Some(match c {
+            "6e" => IsoToleranceClass::E6,
+            "6f" => IsoToleranceClass::F6,
+            "4g" => IsoToleranceClass::G4,
+            "6g" => IsoToleranceClass::G6,
+            "8g" => IsoToleranceClass::G8,
+            "4H" => IsoToleranceClass::H4,
+            "6H" => IsoToleranceClass::H6,
+            _ => return None,
+        }),
+    };
+    Some(ThreadSpec::Metric {
+        major_dia,
+        pitch,
+        class,
+    })
+}
+
+impl FromStr for ThreadSpec {
+    type Err = ParseThreadSpecError;
+
+    /// Parses a thread designation, see `parse_thread_spec`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let spec = if s.starts_with(['M', 'm']) {
+            parse_metric(s)
+        } else {
+            parse_unified(s)
+        };
+        spec.ok_or(ParseThreadSpecError)
+    }
+}
+
+/// Parses a thread designation such as `"1/2-13 UNC 2A"` or `"M10x1.5-6g"`.
+///
+/// Unified designations are the diameter and threads per inch joined by `-`, optionally
+/// followed by the series and class, separated by spaces or `-` (`1/2-13 UNC 2A`,
+/// `1/2-13-UNC-2A`). The series may also be written straight after the threads per inch
+/// (`1/4-20UNC`):
+///
+/// - The diameter is a fraction (`1/2`, `1-1/2`), a decimal (`0.75`) or a number size
+///   (`#10`, or `10`). A bare 1 is one inch; larger whole inch sizes need the inch mark
+///   (`2"`) to tell them from number sizes.
+/// - The series is UNC, UNF, UNEF, UN or UNS, with an R for the rounded root form (UNRC).
+/// - The class is 1A, 2A or 3A for external threads, or 1B, 2B or 3B for internal ones.
+///
+/// Metric designations are `M`, the diameter, `x` and the pitch, optionally followed by
+/// `-` and a tolerance class from `IsoToleranceClass` (`6g`, `6H`...). The pitch is
+/// required. Case is ignored except for the metric class, where it tells external (g)
+/// from internal (H) threads.
+///
+/// # Parameters
+/// - s: The thread designation.
+///
+/// # Returns
+/// - `Result<ThreadSpec, ParseThreadSpecError>`: The parsed thread, or an error if the
+///   designation is not recognized.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::{parse_thread_spec, ThreadSpec};
+/// match parse_thread_spec("1/2-13 UNC 2A").unwrap() {
+///     ThreadSpec::Unified { major_dia, tpi, .. } => assert_eq!((major_dia, tpi), (0.5, 13)),
+///     ThreadSpec::Metric { .. } => unreachable!(),
+/// }
+/// ```
+pub fn parse_thread_spec(s: &str) -> Result<ThreadSpec, ParseThreadSpecError> {
+    s.parse()
+}
+
+/// Standard UNC sizes as (nominal diameter, TPI), from ASME B1.1. Sizes over 1-3/4" have
+/// fractional TPI (4-1/2 for 2") and are left out.
+const UNC_SIZES: [(f64, u32); 24] = [
+    (0.073, 64),
+    (0.086, 56),
+    (0.099, 48),
+    (0.112, 40),
+    (0.125, 40),
+    (0.138, 32),
+    (0.164, 32),
+    (0.190, 24),
+    (0.216, 24),
+    (1.0 / 4.0, 20),
+    (5.0 / 16.0, 18),
+    (3.0 / 8.0, 16),
+    (7.0 / 16.0, 14),
+    (1.0 / 2.0, 13),
+    (9.0 / 16.0, 12),
+    (5.0 / 8.0, 11),
+    (3.0 / 4.0, 10),
+    (7.0 / 8.0, 9),
+    (1.0, 8),
+    (1.125, 7),
+    (1.25, 7),
+    (1.375, 6),
+    (1.5, 6),
+    (1.75, 5),
+];
+
+/// Standard UNF sizes as (nominal diameter, TPI), from ASME B1.1.
+const UNF_SIZES: [(f64, u32); 24] = [
+    (0.060, 80),
+    (0.073, 72),
+    (0.086, 64),
+    (0.099, 56),
+    (0.112, 48),
+    (0.125, 44),
+    (0.138, 40),
+    (0.164, 36),
+    (0.190, 32),
+    (0.216, 28),
+    (1.0 / 4.0, 28),
+    (5.0 / 16.0, 24),
+    (3.0 / 8.0, 24),
+    (7.0 / 16.0, 20),
+    (1.0 / 2.0, 20),
+    (9.0 / 16.0, 18),
+    (5.0 / 8.0, 18),
+    (3.0 / 4.0, 16),
+    (7.0 / 8.0, 14),
+    (1.0, 12),
+    (1.125, 12),
+    (1.25, 12),
+    (1.375, 12),
+    (1.5, 12),
+];
+
+/// Standard UNEF sizes as (nominal diameter, TPI), from ASME B1.1.
+const UNEF_SIZES: [(f64, u32); 25] = [
+    (0.216, 32),
+    (1.0 / 4.0, 32),
+    (5.0 / 16.0, 32),
+    (3.0 / 8.0, 32),
+    (7.0 / 16.0, 28),
+    (1.0 / 2.0, 28),
+    (9.0 / 16.0, 24),
+    (5.0 / 8.0, 24),
+    (11.0 / 16.0, 24),
+    (3.0 / 4.0, 20),
+    (13.0 / 16.0, 20),
+    (7.0 / 8.0, 20),
+    (15.0 / 16.0, 20),
+    (1.0, 20),
+    (1.0625, 18),
+    (1.125, 18),
+    (1.1875, 18),
+    (1.25, 18),
+    (1.3125, 18),
+    (1.375, 18),
+    (1.4375, 18),
+    (1.5, 18),
+    (1.5625, 18),
+    (1.625, 18),
+    (1.6875, 18),
+];
+
+/// The standard sizes of a series, from small to large.
+fn series_sizes(series: UnSeries) -> &'static [(f64, u32)] {
+    match series {
+        UnSeries::Unc => &UNC_SIZES,
+        UnSeries::Unf => &UNF_SIZES,
+        UnSeries::Unef => &UNEF_SIZES,
+        UnSeries::Un => &[],
+    }
+}
+
+/// Looks up the standard threads per inch of a size in a Unified thread series.
+///
+/// Covers the UNC, UNF and UNEF tables of ASME B1.1, including number sizes (#0 to
+/// #12), up to 1-3/4" for UNC and 1-11/16" for UNEF. A diameter within 0.0005" of a
+/// nominal size matches it, so `0.19` finds #10 and `0.3750` finds 3/8".
+///
+/// # Parameters
+/// - diameter: Nominal diameter, in inches.
+/// - series: The thread series.
+///
+/// # Returns
+/// - `Option<(f64, u32)>`: The exact nominal diameter and the TPI, ready for
+///   `calc_uts_extern_thread`. `None` if the size is not in the series, and always for
+///   `UnSeries::Un`, which has no single pitch per size.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::{standard_thread, UnSeries};
+/// assert_eq!(standard_thread(0.5, UnSeries::Unc), Some((0.5, 13)));
+/// ```
+pub fn standard_thread(diameter: f64, series: UnSeries) -> Option<(f64, u32)> {
+    series_sizes(series)
+        .iter()
+        .find(|(d, _)| (d - diameter).abs() < 0.0005)
+        .copied()
+}
+
 /// Calculates the thread allowance for Unified Thread Standard (UTS) external threads.
 ///
 /// The thread allowance is calculated using the formula:
@@ -41,7 +498,7 @@ pub fn calc_uts_allowance(d: f64, p: f64, class: &ThreadClass, le: Option<f64>)
         ThreadClass::A1 | ThreadClass::A2 => 0.3,
         ThreadClass::A3 => return 0.0,
     };
-    n * (k1 * d.cbrt() + k1 * le.sqrt() + k2 * p.powi(2).cbrt())
+    n * (k1 * float::cbrt(d) + k1 * float::sqrt(le) + k2 * float::cbrt(float::powi(p, 2)))
 }
 
 /// Calculates the base tolerance (T) from which other tolerances are derived.
@@ -67,15 +524,15 @@ pub fn calc_uts_allowance(d: f64, p: f64, class: &ThreadClass, le: Option<f64>)
 fn calc_uts_base_tolerance(d: f64, p: f64, le: f64) -> f64 {
     let k1 = 0.0015;
     let k2 = 0.015;
-    k1 * d.cbrt() + k1 * le.sqrt() + k2 * p.powi(2).cbrt()
+    k1 * float::cbrt(d) + k1 * float::sqrt(le) + k2 * float::cbrt(float::powi(p, 2))
 }
 
 fn calc_uts_extern_tolerances(d: f64, p: f64, class: &ThreadClass, le: f64) -> (f64, f64, f64) {
     let t = calc_uts_base_tolerance(d, p, le);
     let td = match class {
         // Tolerance for External Major Diameter
-        ThreadClass::A1 => 0.3 * t,
-        ThreadClass::A2 | ThreadClass::A3 => 0.06 * p.powi(2).cbrt(),
+        ThreadClass::A1 => 0.09 * float::cbrt(float::powi(p, 2)),
+        ThreadClass::A2 | ThreadClass::A3 => 0.06 * float::cbrt(float::powi(p, 2)),
     };
     let td2 = match class {
         // Tolerance for External Pitch Diameter
@@ -93,46 +550,140 @@ fn calc_uts_extern_tolerances(d: f64, p: f64, class: &ThreadClass, le: f64) -> (
 /// pitch, height, and length of engagement. It is used to encapsulate the results
 /// of unified thread calculations.
 pub struct UnifiedThreadCalc {
-    p: f64,         // Pitch
-    d_min: f64,     // Min. Major Dia.
-    d_max: f64,     // Max. Major Dia.
-    d1: f64,        // Minor Dia.
-    d2: f64,        // External Pitch Dia.
-    d2_min: f64,    // Min. Pitch Dia.
-    d2_max: f64,    // Max. Pitch Dia.
-    h: f64,         // Height Triangle
-    es: f64,        // Allowance
-    t: f64,         // Base Tolerance
-    td: f64,        // Major Dia. Tolerance
-    td2: f64,       // Pitch Tolerance
-    le: f64,        // Length of Engagement
-    d_unr_max: f64, // Max. External UNR Dia.
-    d_un_max: f64,  // Max. External UN Dia.
-    h_as: f64,      // External Thread Addendum
+    p: f64,      // Pitch
+    d_min: f64,  // Min. Major Dia.
+    d_max: f64,  // Max. Major Dia.
+    d1: f64,     // Minor Dia.
+    d2: f64,     // External Pitch Dia.
+    d2_min: f64, // Min. Pitch Dia.
+    d2_max: f64, // Max. Pitch Dia.
+    h: f64,      // Height Triangle
+    es: f64,     // Allowance
+    t: f64,      // Base Tolerance
+    td: f64,     // Major Dia. Tolerance
+    td2: f64,    // Pitch Tolerance
+    le: f64,     // Length of Engagement
+    h_as: f64,   // External Thread Addendum
+}
+
+impl UnifiedThreadCalc {
+    /// Pitch (P).
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Length of engagement (LE) the tolerances were calculated for.
+    pub fn le(&self) -> f64 {
+        self.le
+    }
+
+    /// Allowance (es), the clearance between the basic and maximum diameters. Zero for
+    /// class 3A.
+    pub fn es(&self) -> f64 {
+        self.es
+    }
+
+    /// Base tolerance (T) the pitch diameter tolerance is derived from.
+    pub fn t(&self) -> f64 {
+        self.t
+    }
+
+    /// Major diameter tolerance (Td).
+    pub fn td(&self) -> f64 {
+        self.td
+    }
+
+    /// Pitch diameter tolerance (Td2).
+    pub fn td2(&self) -> f64 {
+        self.td2
+    }
+
+    /// Height of the fundamental triangle (H = 0.866025 × P).
+    pub fn h(&self) -> f64 {
+        self.h
+    }
+
+    /// Basic addendum of the external thread (3H/8 = 0.324760 × P), the radial height of
+    /// the thread above the pitch diameter.
+    pub fn h_as(&self) -> f64 {
+        self.h_as
+    }
+
+    /// Minimum major diameter (d max - Td).
+    pub fn d_min(&self) -> f64 {
+        self.d_min
+    }
+
+    /// Maximum major diameter (D - es).
+    pub fn d_max(&self) -> f64 {
+        self.d_max
+    }
+
+    /// Maximum minor diameter for the root form, measured from the maximum major
+    /// diameter.
+    pub fn d1(&self) -> f64 {
+        self.d1
+    }
+
+    /// Basic pitch diameter (D - 0.649519 × P).
+    pub fn d2(&self) -> f64 {
+        self.d2
+    }
+
+    /// Minimum pitch diameter (d2 max - Td2).
+    pub fn d2_min(&self) -> f64 {
+        self.d2_min
+    }
+
+    /// Maximum pitch diameter (d2 - es).
+    pub fn d2_max(&self) -> f64 {
+        self.d2_max
+    }
 }
 
+/// Calculates the limits of a Unified Thread Standard (UTS) external thread.
+///
+/// The maximum diameters are the basic diameters less the allowance `es`, and the
+/// tolerances are taken off them. The minor diameter is given for the root form.
+///
+/// # Parameters
+/// - d: Nominal Diameter (D), in inches.
+/// - tpi: Threads per inch.
+/// - class: The thread class (1A, 2A, or 3A).
+/// - root: The root form, flat (UN) or rounded (UNR).
+/// - le: Length of engagement (LE), in inches. Defaults to `9 × P`. The Machinery's
+///   Handbook tables use `LE = D`; to give the length as a number of pitches, pass
+///   `Some(n as f64 / tpi as f64)`.
+///
+/// # Returns
+/// - `UnifiedThreadCalc`: The thread limits.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::{calc_uts_extern_thread, RootForm, ThreadClass};
+/// let n = calc_uts_extern_thread(0.25, 20, &ThreadClass::A2, &RootForm::Unr, Some(0.25));
+/// assert!((n.d2_max() - 0.2164).abs() < 0.0001);
+/// ```
 pub fn calc_uts_extern_thread(
     d: f64,
     tpi: u32,
     class: &ThreadClass,
-    le: Option<u32>,
+    root: &RootForm,
+    le: Option<f64>,
 ) -> UnifiedThreadCalc {
     let p = 1.0 / tpi as f64;
-    let le = le.unwrap_or(9) as f64 * p;
+    let le = le.unwrap_or(9.0 * p);
     let es = calc_uts_allowance(d, p, class, Some(le));
     let d_max = d - es;
     let (t, td, td2) = calc_uts_extern_tolerances(d, p, class, le);
     let d_min = d_max - td;
-    let h = 0.866025404 * p;
-    let d2 = d - 2.0 * ((3.0 / 8.0) * h);
+    let h = SQRT3_OVER_2 * p;
+    let d2 = d - PITCH_DIA_FACTOR * p;
     let d2_max = d2 - es;
     let d2_min = d2_max - td2;
-    let d_bsc_min = d - 2.0 * ((5.0 / 8.0) * h);
-    let d1 = match class {
-        // UN Series
-        ThreadClass::A1 | ThreadClass::A2 => d_bsc_min - es,
-        ThreadClass::A3 => d_bsc_min,
-        // UNR ^-H/8
+    let d1 = match root {
+        RootForm::Un => d_max - UN_MINOR_FACTOR * p,
+        RootForm::Unr => d_max - UNR_MINOR_FACTOR * p,
     };
     UnifiedThreadCalc {
         p,
@@ -148,42 +699,1529 @@ pub fn calc_uts_extern_thread(
         d2_max,
         h,
         d1,
-        d_unr_max: 1.19078493 * p,
-        d_un_max: 1.08253175 * p,
-        h_as: 0.64951905 * p, // ((d - 5.0 / 8.0) * 3f64.sqrt() * p).abs()
+        h_as: PITCH_DIA_FACTOR / 2.0 * p,
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Calculated limits of a Unified internal (nut) thread. All values are in inches.
+#[derive(Debug, Default)]
+pub struct UnifiedInternThreadCalc {
+    p: f64,
+    le: f64,
+    t: f64,
+    td1: f64,
+    td2: f64,
+    d_min: f64,
+    d1_min: f64,
+    d1_max: f64,
+    d2_min: f64,
+    d2_max: f64,
+}
 
-    #[test]
-    fn test_calc_uts_thread_allowance() {
-        let pitch = 1.0 / 28.0;
-        let es = truncate_float(
-            calc_uts_allowance(0.5, pitch, &ThreadClass::A2, Some(0.4)),
-            6,
-        );
-        assert_eq!(es, 0.00113);
+impl UnifiedInternThreadCalc {
+    /// Pitch (P).
+    pub fn p(&self) -> f64 {
+        self.p
+    }
 
-        let pitch = 1.0 / 20.0;
-        let es = truncate_float(
-            calc_uts_allowance(0.25, pitch, &ThreadClass::A1, Some(0.0125)),
-            6,
-        );
-        assert_eq!(es, 0.000945);
+    /// Length of engagement (LE).
+    pub fn le(&self) -> f64 {
+        self.le
+    }
 
-        let es = calc_uts_allowance(0.25, pitch, &ThreadClass::A3, Some(0.0125));
-        assert_eq!(es, 0.0);
+    /// Base tolerance (T) the pitch diameter tolerance is derived from.
+    pub fn t(&self) -> f64 {
+        self.t
     }
 
-    #[test]
-    fn test_calc_uts_extern_thread() {
-        let n = calc_uts_extern_thread(0.5, 28, &ThreadClass::A2, Some(9));
-        println!("{:?}", n);
+    /// Minor diameter tolerance (TD1).
+    pub fn td1(&self) -> f64 {
+        self.td1
+    }
 
-        let n = calc_uts_extern_thread(0.25, 20, &ThreadClass::A2, Some(9));
-        println!("{:?}", n);
+    /// Pitch diameter tolerance (TD2).
+    pub fn td2(&self) -> f64 {
+        self.td2
+    }
+
+    /// Minimum major diameter, the basic major diameter (D).
+    pub fn d_min(&self) -> f64 {
+        self.d_min
+    }
+
+    /// Minimum minor diameter, the basic minor diameter (D - 1.082532 × P).
+    pub fn d1_min(&self) -> f64 {
+        self.d1_min
+    }
+
+    /// Maximum minor diameter (D1 min + TD1).
+    pub fn d1_max(&self) -> f64 {
+        self.d1_max
+    }
+
+    /// Minimum pitch diameter, the basic pitch diameter (D - 0.649519 × P).
+    pub fn d2_min(&self) -> f64 {
+        self.d2_min
+    }
+
+    /// Maximum pitch diameter (D2 min + TD2).
+    pub fn d2_max(&self) -> f64 {
+        self.d2_max
+    }
+}
+
+/// Calculates the minor diameter tolerance of a UN internal thread.
+///
+/// ```markdown
+/// TD1 = 0.05 × ³√P² + 0.03 × P / D - 0.002
+/// ```
+///
+/// bounded below by `0.25P - 0.4P²` (1B, 2B) or `0.23P - 1.5P²` (3B) for 13 TPI and
+/// coarser, or `0.120P` for finer pitches, and above by `0.394P`.
+fn calc_uts_intern_minor_tolerance(d: f64, p: f64, class: ThreadClassInternal) -> f64 {
+    let td1 = 0.05 * float::cbrt(float::powi(p, 2)) + 0.03 * p / d - 0.002;
+    let min = if p >= 1.0 / 13.0 - 1e-12 {
+        match class {
+            ThreadClassInternal::B1 | ThreadClassInternal::B2 => 0.25 * p - 0.4 * p * p,
+            ThreadClassInternal::B3 => 0.23 * p - 1.5 * p * p,
+        }
+    } else {
+        0.120 * p
+    };
+    td1.max(min).min(0.394 * p)
+}
+
+/// Calculates the limits of a Unified Thread Standard (UTS) internal thread.
+///
+/// Internal threads have no allowance, so the minimum minor and pitch diameters are the
+/// basic values and the tolerances are added to them. The pitch diameter tolerance is a
+/// multiple of the base tolerance `T` used for external threads:
+///
+/// ```markdown
+/// TD2 = 1.95 × T (1B),  1.3 × T (2B),  0.975 × T (3B)
+/// ```
+///
+/// The minor diameter tolerance is `0.05 × ³√P² + 0.03 × P / D - 0.002`, held within
+/// the limits for the class, and applies to sizes of 1/4" and larger.
+///
+/// # Parameters
+/// - d: Nominal Diameter (D), in inches.
+/// - tpi: Threads per inch.
+/// - class: The thread class (1B, 2B, or 3B).
+/// - le: Length of engagement (LE), in inches. Defaults to `9 × P`.
+///
+/// # Returns
+/// - `UnifiedInternThreadCalc`: The thread limits.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::{calc_uts_intern_thread, ThreadClassInternal};
+/// let n = calc_uts_intern_thread(0.25, 20, ThreadClassInternal::B2, Some(0.25));
+/// assert!(n.d1_max() > n.d1_min());
+/// ```
+pub fn calc_uts_intern_thread(
+    d: f64,
+    tpi: u32,
+    class: ThreadClassInternal,
+    le: Option<f64>,
+) -> UnifiedInternThreadCalc {
+    let p = 1.0 / tpi as f64;
+    let le = le.unwrap_or(9.0 * p);
+    let t = calc_uts_base_tolerance(d, p, le);
+    let td2 = match class {
+        ThreadClassInternal::B1 => 1.95 * t,
+        ThreadClassInternal::B2 => 1.3 * t,
+        ThreadClassInternal::B3 => 0.975 * t,
+    };
+    let td1 = calc_uts_intern_minor_tolerance(d, p, class);
+    let d1_min = d - BASIC_MINOR_FACTOR * p;
+    let d2_min = d - PITCH_DIA_FACTOR * p;
+    UnifiedInternThreadCalc {
+        p,
+        le,
+        t,
+        td1,
+        td2,
+        d_min: d,
+        d1_min,
+        d1_max: d1_min + td1,
+        d2_min,
+        d2_max: d2_min + td2,
+    }
+}
+
+/// Tolerance classes for ISO metric external threads (ISO 965-1).
+///
+/// The letter is the tolerance position, which sets the fundamental deviation
+/// (allowance) of the pitch and major diameters, and the number is the tolerance grade
+/// of both diameters.
+///
+/// - E6: 6e, large allowance for thick coatings.
+/// - F6: 6f, allowance for plating.
+/// - G4, G6, G8: 4g, 6g (the general purpose class) and 8g.
+/// - H4, H6: 4h and 6h, no allowance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsoToleranceClass {
+    E6,
+    F6,
+    G4,
+    G6,
+    G8,
+    H4,
+    H6,
+}
+
+impl IsoToleranceClass {
+    /// Returns the fundamental deviation coefficients `(a, b)` in µm, where
+    /// `es = -(a + b × P)`, and the tolerance grade.
+    fn parts(self) -> ((f64, f64), u32) {
+        const E: (f64, f64) = (50.0, 11.0);
+        const F: (f64, f64) = (30.0, 11.0);
+        const G: (f64, f64) = (15.0, 11.0);
+        const H: (f64, f64) = (0.0, 0.0);
+        match self {
+            IsoToleranceClass::E6 => (E, 6),
+            IsoToleranceClass::F6 => (F, 6),
+            IsoToleranceClass::G4 => (G, 4),
+            IsoToleranceClass::G6 => (G, 6),
+            IsoToleranceClass::G8 => (G, 8),
+            IsoToleranceClass::H4 => (H, 4),
+            IsoToleranceClass::H6 => (H, 6),
+        }
+    }
+}
+
+/// Rounds a tolerance in µm to the nearest value of the R40 preferred number series,
+/// as the ISO 965-1 tables do.
+fn round_r40(um: f64) -> f64 {
+    const R40: [f64; 41] = [
+        1.0, 1.06, 1.12, 1.18, 1.25, 1.32, 1.4, 1.5, 1.6, 1.7, 1.8, 1.9, 2.0, 2.12, 2.24, 2.36,
+        2.5, 2.65, 2.8, 3.0, 3.15, 3.35, 3.55, 3.75, 4.0, 4.25, 4.5, 4.75, 5.0, 5.3, 5.6, 6.0, 6.3,
+        6.7, 7.1, 7.5, 8.0, 8.5, 9.0, 9.5, 10.0,
+    ];
+    let mut decade = 1.0;
+    while um >= 10.0 * decade {
+        decade *= 10.0;
+    }
+    R40.iter()
+        .map(|m| m * decade)
+        .min_by(|a, b| (a - um).abs().total_cmp(&(b - um).abs()))
+        .unwrap()
+}
+
+/// Returns the multiplier applied to the grade 6 tolerance for a tolerance grade.
+fn iso_grade_factor(grade: u32) -> f64 {
+    match grade {
+        ..=3 => 0.5,
+        4 => 0.63,
+        5 => 0.8,
+        6 => 1.0,
+        7 => 1.25,
+        8 => 1.6,
+        _ => 2.0,
+    }
+}
+
+/// Returns the geometric mean of the ISO 965-1 diameter range containing `d` (mm).
+fn iso_diameter_step(d: f64) -> f64 {
+    const STEPS: [f64; 11] = [
+        0.99, 1.4, 2.8, 5.6, 11.2, 22.4, 45.0, 90.0, 180.0, 355.0, 600.0,
+    ];
+    let i = STEPS[1..]
+        .iter()
+        .position(|upper| d <= *upper)
+        .unwrap_or(STEPS.len() - 2);
+    float::sqrt(STEPS[i] * STEPS[i + 1])
+}
+
+/// Calculated limits of an ISO metric external thread. All values are in millimeters.
+#[derive(Debug, Default)]
+pub struct IsoThreadCalc {
+    p: f64,
+    le: f64,
+    es: f64,
+    td: f64,
+    td2: f64,
+    d_max: f64,
+    d_min: f64,
+    d2: f64,
+    d2_max: f64,
+    d2_min: f64,
+    d3_max: f64,
+}
+
+impl IsoThreadCalc {
+    /// Pitch (P).
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Length of engagement (LE) used to select the pitch diameter grade.
+    pub fn le(&self) -> f64 {
+        self.le
+    }
+
+    /// Fundamental deviation (es), the upper deviation of the major and pitch diameters.
+    /// Zero for h classes and negative otherwise.
+    pub fn es(&self) -> f64 {
+        self.es
+    }
+
+    /// Major diameter tolerance (Td).
+    pub fn td(&self) -> f64 {
+        self.td
+    }
+
+    /// Pitch diameter tolerance (Td2).
+    pub fn td2(&self) -> f64 {
+        self.td2
+    }
+
+    /// Maximum major diameter (d + es).
+    pub fn d_max(&self) -> f64 {
+        self.d_max
+    }
+
+    /// Minimum major diameter (d_max - Td).
+    pub fn d_min(&self) -> f64 {
+        self.d_min
+    }
+
+    /// Basic pitch diameter (d2 = d - 0.649519 × P).
+    pub fn d2(&self) -> f64 {
+        self.d2
+    }
+
+    /// Maximum pitch diameter (d2 + es).
+    pub fn d2_max(&self) -> f64 {
+        self.d2_max
+    }
+
+    /// Minimum pitch diameter (d2_max - Td2).
+    pub fn d2_min(&self) -> f64 {
+        self.d2_min
+    }
+
+    /// Maximum minor diameter at the root (d3 = d - 1.226869 × P, plus es).
+    pub fn d3_max(&self) -> f64 {
+        self.d3_max
+    }
+}
+
+/// Calculates the limits of an ISO metric external thread (ISO 965-1).
+///
+/// The fundamental deviation and grade 6 tolerances are:
+///
+/// ```markdown
+/// es     = -(a + 11 × P)                         a = 50 (e), 30 (f), 15 (g), 0 (h)
+/// Td(6)  = 180 × P^(2/3) - 3.15 / √P
+/// Td2(6) = 90 × P^0.4 × d^0.1
+/// ```
+///
+/// in µm, with P in mm and `d` the geometric mean of the standard diameter range
+/// containing the nominal diameter. Other grades scale the grade 6 values (0.63 for
+/// grade 4, 1.6 for grade 8) and tolerances are rounded to the R40 series as in the
+/// standard's tables. The fundamental deviation is rounded to a whole µm, which can
+/// differ from the published tables by 1 µm for some pitches.
+///
+/// The tolerances apply to the normal length of engagement group N
+/// (2.24 × P × d^0.2 to 6.7 × P × d^0.2). Following the recommended tolerance classes,
+/// a shorter engagement uses the next finer pitch diameter grade and a longer one the
+/// next coarser grade.
+///
+/// # Parameters
+/// - d: Nominal (major) diameter, in mm.
+/// - pitch: Pitch, in mm.
+/// - tol_class: Tolerance class, e.g. `IsoToleranceClass::G6` for 6g.
+/// - le: Length of engagement, in mm. Defaults to the start of the normal group.
+///
+/// # Returns
+/// - `IsoThreadCalc`: The thread limits.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::{calc_iso_extern_thread, IsoToleranceClass};
+/// let m6 = calc_iso_extern_thread(6.0, 1.0, IsoToleranceClass::G6, None);
+/// assert!((m6.d_max() - 5.974).abs() < 1e-9);
+/// ```
+pub fn calc_iso_extern_thread(
+    d: f64,
+    pitch: f64,
+    tol_class: IsoToleranceClass,
+    le: Option<f64>,
+) -> IsoThreadCalc {
+    let p = pitch;
+    let ((a, b), grade) = tol_class.parts();
+    let ln = p * float::powf(d, 0.2);
+    let le = le.unwrap_or(2.24 * ln);
+    let pd_grade = if le < 2.24 * ln {
+        grade - 1
+    } else if le > 6.7 * ln {
+        grade + 1
+    } else {
+        grade
+    };
+
+    let es = -float::round(a + b * p) / 1000.0;
+    let td6 = 180.0 * float::powf(p, 2.0 / 3.0) - 3.15 / float::sqrt(p);
+    let td = round_r40(td6 * iso_grade_factor(grade)) / 1000.0;
+    let td2_6 = 90.0 * float::powf(p, 0.4) * float::powf(iso_diameter_step(d), 0.1);
+    let td2 = round_r40(td2_6 * iso_grade_factor(pd_grade)) / 1000.0;
+
+    let d2 = d - PITCH_DIA_FACTOR * p;
+    let d_max = d + es;
+    let d2_max = d2 + es;
+    IsoThreadCalc {
+        p,
+        le,
+        es,
+        td,
+        td2,
+        d_max,
+        d_min: d_max - td,
+        d2,
+        d2_max,
+        d2_min: d2_max - td2,
+        // Root radius of H/6 as for UNR threads.
+        d3_max: d - UNR_MINOR_FACTOR * p + es,
+    }
+}
+
+/// General purpose Acme thread classes (ASME B1.5).
+///
+/// - G2: 2G, the standard class for most assemblies.
+/// - G3: 3G, less backlash.
+/// - G4: 4G, the least backlash of the general purpose classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcmeClass {
+    G2,
+    G3,
+    G4,
+}
+
+/// Calculated dimensions of an external general purpose Acme thread. All values are in
+/// inches.
+#[derive(Debug, Default)]
+pub struct AcmeThreadCalc {
+    p: f64,
+    depth: f64,
+    flat: f64,
+    d_max: f64,
+    d_min: f64,
+    d1: f64,
+    d2: f64,
+    d2_max: f64,
+    d2_min: f64,
+}
+
+impl AcmeThreadCalc {
+    /// Pitch (P).
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Thread depth of the screw, 0.5 × P plus the minor diameter clearance.
+    pub fn depth(&self) -> f64 {
+        self.depth
+    }
+
+    /// Basic width of the flat at the crest and root, 0.3707 × P.
+    pub fn flat(&self) -> f64 {
+        self.flat
+    }
+
+    /// Maximum major diameter, the nominal diameter.
+    pub fn d_max(&self) -> f64 {
+        self.d_max
+    }
+
+    /// Minimum major diameter (D - 0.05 × P).
+    pub fn d_min(&self) -> f64 {
+        self.d_min
+    }
+
+    /// Maximum minor diameter (D - 2 × depth).
+    pub fn d1(&self) -> f64 {
+        self.d1
+    }
+
+    /// Basic pitch diameter (D - 0.5 × P).
+    pub fn d2(&self) -> f64 {
+        self.d2
+    }
+
+    /// Maximum pitch diameter, the basic pitch diameter less the class allowance.
+    pub fn d2_max(&self) -> f64 {
+        self.d2_max
+    }
+
+    /// Minimum pitch diameter.
+    pub fn d2_min(&self) -> f64 {
+        self.d2_min
+    }
+}
+
+/// Calculates the dimensions of an external general purpose Acme (29°) thread.
+///
+/// The screw is cut 0.010" deeper than the basic half pitch for 10 TPI and finer, and
+/// 0.020" deeper for coarser pitches, to clear the nut's major diameter. The pitch
+/// diameter allowance and tolerance depend on the class:
+///
+/// ```markdown
+/// allowance:  2G = 0.008 × √D     3G = 0.006 × √D     4G = 0.004 × √D
+/// tolerance:  2G = 0.030 × √P + 0.006 × √D
+///             3G = 0.014 × √P + 0.0028 × √D
+///             4G = 0.010 × √P + 0.002 × √D
+/// ```
+///
+/// # Parameters
+/// - major_dia: Nominal (major) diameter (D), in inches.
+/// - tpi: Threads per inch.
+/// - class: The thread class (2G, 3G, or 4G).
+///
+/// # Returns
+/// - `AcmeThreadCalc`: The thread dimensions.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::{calc_acme_thread, AcmeClass};
+/// let acme = calc_acme_thread(0.5, 10, AcmeClass::G2);
+/// assert!((acme.d1() - 0.38).abs() < 1e-9);
+/// ```
+pub fn calc_acme_thread(major_dia: f64, tpi: u32, class: AcmeClass) -> AcmeThreadCalc {
+    let d = major_dia;
+    let p = 1.0 / tpi as f64;
+    let clearance = if tpi >= 10 { 0.010 } else { 0.020 };
+    let depth = 0.5 * p + clearance;
+    let (allowance, tolerance) = match class {
+        AcmeClass::G2 => (0.008, (0.030, 0.006)),
+        AcmeClass::G3 => (0.006, (0.014, 0.0028)),
+        AcmeClass::G4 => (0.004, (0.010, 0.002)),
+    };
+    let d2 = d - 0.5 * p;
+    let d2_max = d2 - allowance * float::sqrt(d);
+    let td2 = tolerance.0 * float::sqrt(p) + tolerance.1 * float::sqrt(d);
+    AcmeThreadCalc {
+        p,
+        depth,
+        flat: 0.3707 * p,
+        d_max: d,
+        d_min: d - 0.05 * p,
+        d1: d - 2.0 * depth,
+        d2,
+        d2_max,
+        d2_min: d2_max - td2,
+    }
+}
+
+/// A row of the NPT (ASME B1.20.1) dimension table.
+struct NptSize {
+    nominal: &'static str,
+    tpi: f64,
+    od: f64,
+    e0: f64,
+    l1: f64,
+    tap_drill: DrillSize,
+}
+
+const fn npt(
+    nominal: &'static str,
+    tpi: f64,
+    od: f64,
+    e0: f64,
+    l1: f64,
+    drill: (&'static str, f64),
+) -> NptSize {
+    NptSize {
+        nominal,
+        tpi,
+        od,
+        e0,
+        l1,
+        tap_drill: DrillSize {
+            designation: drill.0,
+            dia: drill.1,
+        },
+    }
+}
+
+const NPT_SIZES: [NptSize; 10] = [
+    npt("1/16", 27.0, 0.3125, 0.27118, 0.160, ("C", 0.242)),
+    npt("1/8", 27.0, 0.405, 0.36351, 0.1615, ("R", 0.339)),
+    npt("1/4", 18.0, 0.540, 0.47739, 0.2278, ("7/16\"", 0.4375)),
+    npt("3/8", 18.0, 0.675, 0.61201, 0.240, ("37/64\"", 0.578125)),
+    npt("1/2", 14.0, 0.840, 0.75843, 0.320, ("23/32\"", 0.71875)),
+    npt("3/4", 14.0, 1.050, 0.96768, 0.339, ("59/64\"", 0.921875)),
+    npt("1", 11.5, 1.315, 1.21363, 0.400, ("1-5/32\"", 1.15625)),
+    npt("1-1/4", 11.5, 1.660, 1.55713, 0.420, ("1-1/2\"", 1.5)),
+    npt(
+        "1-1/2",
+        11.5,
+        1.900,
+        1.79609,
+        0.420,
+        ("1-47/64\"", 1.734375),
+    ),
+    npt("2", 11.5, 2.375, 2.26902, 0.436, ("2-7/32\"", 2.21875)),
+];
+
+/// Calculated dimensions of an NPT tapered pipe thread. All lengths are in inches.
+#[derive(Debug)]
+pub struct NptThreadCalc {
+    tpi: f64,
+    od: f64,
+    e0: f64,
+    e1: f64,
+    half_angle: f64,
+    hand_tight: f64,
+    wrench_tight: f64,
+    tap_drill: DrillSize,
+}
+
+impl NptThreadCalc {
+    /// Threads per inch. Sizes 1" and up use 11-1/2 TPI.
+    pub fn tpi(&self) -> f64 {
+        self.tpi
+    }
+
+    /// Pitch (P).
+    pub fn p(&self) -> f64 {
+        1.0 / self.tpi
+    }
+
+    /// Outside diameter of the pipe.
+    pub fn od(&self) -> f64 {
+        self.od
+    }
+
+    /// Pitch diameter at the small end of the external thread (E0).
+    pub fn e0(&self) -> f64 {
+        self.e0
+    }
+
+    /// Pitch diameter at the gauge plane, the hand-tight length from the small end (E1).
+    pub fn e1(&self) -> f64 {
+        self.e1
+    }
+
+    /// Taper half-angle in degrees, measured from the thread axis.
+    pub fn half_angle(&self) -> f64 {
+        self.half_angle
+    }
+
+    /// Hand-tight engagement length (L1).
+    pub fn hand_tight(&self) -> f64 {
+        self.hand_tight
+    }
+
+    /// Wrench-tight engagement length, the hand-tight length plus three turns of
+    /// wrench makeup (L1 + L3).
+    pub fn wrench_tight(&self) -> f64 {
+        self.wrench_tight
+    }
+
+    /// Recommended tap drill for tapping without a taper reamer.
+    pub fn tap_drill(&self) -> DrillSize {
+        self.tap_drill
+    }
+}
+
+/// Looks up the dimensions of an NPT (American National Standard Taper Pipe) thread.
+///
+/// NPT sizes are defined by table rather than by formula. The taper is 3/4" per foot on
+/// the diameter, so the pitch diameter at the gauge plane is:
+///
+/// ```markdown
+/// E1 = E0 + L1 / 16
+/// half-angle = atan(1 / 32) = 1.7899°
+/// ```
+///
+/// # Parameters
+/// - nominal_size: Nominal pipe size, from "1/16" through "2".
+///
+/// # Returns
+/// - `Option<NptThreadCalc>`: The thread dimensions, or `None` for an unknown size.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::calc_npt_thread;
+/// let npt = calc_npt_thread("1/4").unwrap();
+/// assert_eq!(npt.tpi(), 18.0);
+/// assert_eq!(npt.tap_drill().designation, "7/16\"");
+/// ```
+pub fn calc_npt_thread(nominal_size: &str) -> Option<NptThreadCalc> {
+    let size = NPT_SIZES
+        .iter()
+        .find(|s| s.nominal == nominal_size.trim())?;
+    let p = 1.0 / size.tpi;
+    Some(NptThreadCalc {
+        tpi: size.tpi,
+        od: size.od,
+        e0: size.e0,
+        e1: size.e0 + size.l1 / 16.0,
+        half_angle: float::atan2(1.0, 32.0).to_degrees(),
+        hand_tight: size.l1,
+        wrench_tight: size.l1 + 3.0 * p,
+        tap_drill: size.tap_drill,
+    })
+}
+
+/// Calculates the tap drill diameter for a target percentage of thread.
+///
+/// ```markdown
+/// drill = D - (thread % / 100) × (1.299 / TPI)
+/// ```
+///
+/// # Parameters
+/// - major_dia: Nominal (major) diameter of the thread, in inches.
+/// - tpi: Threads per inch.
+/// - thread_pct: Target thread engagement in percent; 75 is typical, see
+///   `recommended_thread_pct`.
+///
+/// # Returns
+/// - `f64`: Tap drill diameter, in inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::calc_tap_drill;
+/// let drill = calc_tap_drill(0.25, 20, 75.0);
+/// assert!((drill - 0.2013).abs() < 0.0001);
+/// ```
+pub fn calc_tap_drill(major_dia: f64, tpi: u32, thread_pct: f64) -> f64 {
+    major_dia - thread_pct / 100.0 * (UN_MINOR_FACTOR / tpi as f64)
+}
+
+/// One size of a drill and tap chart, see `tap_chart`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TapChartRow {
+    /// Nominal (major) diameter, in inches.
+    pub major_dia: f64,
+    pub tpi: u32,
+    /// Calculated tap drill diameter for 75% thread, in inches.
+    pub tap_dia: f64,
+    /// The standard drill closest to `tap_dia`, or `None` if it is larger than every
+    /// drill in `DrillSet::All`.
+    pub tap_drill: Option<DrillSize>,
+    /// The smallest standard drill for a normal fit clearance hole, from
+    /// `clearance_drill`, or `None` if the size is not in its table or the hole is
+    /// larger than every drill in `DrillSet::All`.
+    pub clearance_drill: Option<DrillSize>,
+}
+
+/// Builds a drill and tap chart for the standard sizes of a thread series.
+///
+/// Each size from `standard_thread`'s tables that falls in `range` gets its 75% tap
+/// drill from `calc_tap_drill` and a clearance drill. The tap drill is the closest
+/// standard size, as on the usual charts (#7 for 1/4-20); the clearance drill is
+/// rounded up with `nearest_drill`.
+///
+/// # Parameters
+/// - series: The thread series. `UnSeries::Un` has no standard sizes and gives an empty
+///   chart.
+/// - range: The nominal diameters to include, in inches.
+///
+/// # Returns
+/// - `Vec<TapChartRow>`: One row per size, from small to large.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::{tap_chart, UnSeries};
+/// let chart = tap_chart(UnSeries::Unc, 0.25..=0.5);
+/// assert_eq!(chart.len(), 5);
+/// assert_eq!(chart[0].tap_drill.unwrap().designation, "#7");
+/// ```
+#[cfg(feature = "std")]
+pub fn tap_chart(series: UnSeries, range: core::ops::RangeInclusive<f64>) -> Vec<TapChartRow> {
+    series_sizes(series)
+        .iter()
+        .filter(|(d, _)| range.contains(d))
+        .map(|&(major_dia, tpi)| {
+            let tap_dia = calc_tap_drill(major_dia, tpi, 75.0);
+            let tap_drill = nearest_drill(tap_dia, DrillSet::All)
+                .map(|_| closest_drill(tap_dia, DrillSet::All));
+            TapChartRow {
+                major_dia,
+                tpi,
+                tap_dia,
+                tap_drill,
+                clearance_drill: clearance_drill(major_dia, ClearanceFit::Normal)
+                    .and_then(|dia| nearest_drill(dia, DrillSet::All)),
+            }
+        })
+        .collect()
+}
+
+/// Returns a typical percentage of thread to tap in a material.
+///
+/// Beyond about 75% the strength of a tapped thread hardly improves while the tapping
+/// torque rises steeply, so tough and work-hardening materials are tapped shallower.
+///
+/// # Parameters
+/// - material: The workpiece material.
+///
+/// # Returns
+/// - `f64`: Thread engagement in percent, for use with `calc_tap_drill`.
+///
+/// # Example
+/// ```rust
+/// use smithy::speeds::Material;
+/// use smithy::threading::recommended_thread_pct;
+/// assert_eq!(recommended_thread_pct(Material::MildSteel), 75.0);
+/// ```
+pub fn recommended_thread_pct(material: Material) -> f64 {
+    match material {
+        Material::Aluminum | Material::Brass | Material::Plastic | Material::MildSteel => 75.0,
+        Material::CastIron => 70.0,
+        Material::AlloySteel => 65.0,
+        Material::StainlessSteel => 60.0,
+        Material::Titanium => 55.0,
+    }
+}
+
+/// Calculates the percentage of thread engaged from a measured internal minor diameter.
+///
+/// The percentage uses the same depth basis as `calc_tap_drill`, so a hole drilled with
+/// its drill for a given percentage reads back that percentage:
+///
+/// ```markdown
+/// % = (D - measured minor) / (1.299 × P) × 100
+/// ```
+///
+/// # Parameters
+/// - measured_minor: Measured minor diameter of the tapped hole, in inches.
+/// - d: Nominal (major) diameter of the thread, in inches.
+/// - tpi: Threads per inch.
+///
+/// # Returns
+/// - `f64`: Percent thread engagement, clamped to `0.0..=100.0` so undersize or
+///   oversize measurements still report a usable figure.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::engagement_from_minor;
+/// assert_eq!(engagement_from_minor(0.25, 0.25, 20), 0.0);
+/// ```
+pub fn engagement_from_minor(measured_minor: f64, d: f64, tpi: u32) -> f64 {
+    let p = 1.0 / tpi as f64;
+    ((d - measured_minor) / (UN_MINOR_FACTOR * p) * 100.0).clamp(0.0, 100.0)
+}
+
+/// Calculates the measurement over three wires for checking a thread's pitch diameter.
+///
+/// Two wires sit in adjacent grooves on one side of the thread and one on the other.
+/// The measurement over them is:
+///
+/// ```markdown
+/// M = E + W × (1 + 1 / sin(α / 2)) - cot(α / 2) × P / 2
+/// ```
+///
+/// where `E` is the pitch diameter, `W` the wire diameter, `α` the included thread angle
+/// and `P` the pitch. The formula ignores the small effect of the helix angle.
+///
+/// # Parameters
+/// - pitch_dia: Pitch diameter of the thread (E), in inches.
+/// - tpi: Threads per inch.
+/// - wire_dia: Diameter of the measuring wires (W), in inches.
+/// - thread_angle_deg: Included angle of the thread form (α), e.g. 60 for UTS and ISO.
+///
+/// # Returns
+/// - `f64`: The measurement over the wires, in inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::{best_wire_size, three_wire_measurement};
+/// let m = three_wire_measurement(0.45, 13, best_wire_size(13), 60.0);
+/// assert!((m - 0.5166).abs() < 0.0001);
+/// ```
+pub fn three_wire_measurement(
+    pitch_dia: f64,
+    tpi: u32,
+    wire_dia: f64,
+    thread_angle_deg: f64,
+) -> f64 {
+    let p = 1.0 / tpi as f64;
+    let half = (thread_angle_deg / 2.0).to_radians();
+    pitch_dia + wire_dia * (1.0 + 1.0 / float::sin(half)) - p / (2.0 * float::tan(half))
+}
+
+/// Calculates the best wire size for measuring a 60° thread over three wires.
+///
+/// The best size touches the flanks exactly at the pitch diameter, which makes the
+/// measurement least sensitive to errors in the thread angle:
+///
+/// ```markdown
+/// W = P / (2 × cos(30°)) = 0.57735 × P
+/// ```
+///
+/// # Parameters
+/// - tpi: Threads per inch.
+///
+/// # Returns
+/// - `f64`: The best wire diameter, in inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::best_wire_size;
+/// assert!((best_wire_size(13) - 0.04441).abs() < 0.00001);
+/// ```
+pub fn best_wire_size(tpi: u32) -> f64 {
+    let p = 1.0 / tpi as f64;
+    p / (2.0 * float::cos(30_f64.to_radians()))
+}
+
+/// Recommended relief groove and lead-in chamfer for an external thread. All values are
+/// in inches.
+#[derive(Debug, Default)]
+pub struct ReliefSpec {
+    groove_width: f64,
+    groove_dia: f64,
+    groove_depth: f64,
+    chamfer: f64,
+}
+
+impl ReliefSpec {
+    /// Axial width of the relief groove, 1.5 × P.
+    pub fn groove_width(&self) -> f64 {
+        self.groove_width
+    }
+
+    /// Diameter at the bottom of the groove, 0.1 × P below the minor diameter.
+    pub fn groove_dia(&self) -> f64 {
+        self.groove_dia
+    }
+
+    /// Radial depth of the groove from the nominal diameter.
+    pub fn groove_depth(&self) -> f64 {
+        self.groove_depth
+    }
+
+    /// Length of the 45° lead-in chamfer, equal to the thread depth.
+    pub fn chamfer(&self) -> f64 {
+        self.chamfer
+    }
+}
+
+/// Calculates a relief groove and lead-in chamfer to turn before cutting an external
+/// thread.
+///
+/// The groove gives the tool room to finish each pass and is cut just below the minor
+/// diameter of a class 2A UN thread from `calc_uts_extern_thread`, so the last thread runs
+/// out cleanly. The 45° chamfer is as long as the thread is deep, so it reaches the root:
+///
+/// ```markdown
+/// groove width = 1.5 × P
+/// groove dia   = d1 - 0.1 × P
+/// chamfer      = (d max - d1) / 2
+/// ```
+///
+/// # Parameters
+/// - major_dia: Nominal (major) diameter (D), in inches.
+/// - tpi: Threads per inch.
+///
+/// # Returns
+/// - `ReliefSpec`: The groove and chamfer dimensions.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::thread_relief;
+/// let relief = thread_relief(0.5, 13);
+/// assert!((relief.groove_width() - 0.1154).abs() < 0.0001);
+/// ```
+pub fn thread_relief(major_dia: f64, tpi: u32) -> ReliefSpec {
+    let thread = calc_uts_extern_thread(major_dia, tpi, &ThreadClass::A2, &RootForm::Un, None);
+    let p = thread.p();
+    let groove_dia = thread.d1() - 0.1 * p;
+    ReliefSpec {
+        groove_width: 1.5 * p,
+        groove_dia,
+        groove_depth: (major_dia - groove_dia) / 2.0,
+        chamfer: (thread.d_max() - thread.d1()) / 2.0,
+    }
+}
+
+/// Calculates the minimum length of engagement for a screw to break before its threads
+/// strip.
+///
+/// Uses the FED-STD-H28 shear area formulas for a class 2A screw in a class 2B nut,
+/// with the least material limits of both threads:
+///
+/// ```markdown
+/// At = π/4 × (D - 0.9743 / n)²
+/// Le = 2 × At / (π × Kn max × (1/2 + 0.57735 × n × (Es min - Kn max)))
+/// As = π × n × Le × Kn max × (1/(2n) + 0.57735 × (Es min - Kn max))
+/// An = π × n × Le × Ds min × (1/(2n) + 0.57735 × (Ds min - En max))
+/// J  = As × external_uts / (An × internal_uts)
+/// ```
+///
+/// `Le` makes the external threads as strong as the screw. When the internal material is
+/// weaker (J > 1), the length is increased to `J × Le` so the internal threads are as
+/// well.
+///
+/// # Parameters
+/// - major_dia: Nominal (major) diameter (D), in inches.
+/// - tpi: Threads per inch (n).
+/// - external_uts: Ultimate tensile strength of the screw material.
+/// - internal_uts: Ultimate tensile strength of the nut or tapped part, in the same unit.
+///
+/// # Returns
+/// - `f64`: The minimum length of engagement, in inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::threading::min_engagement_length;
+/// // A steel screw in aluminum needs much more thread than in steel.
+/// assert!(min_engagement_length(0.5, 13, 150.0, 45.0) > min_engagement_length(0.5, 13, 150.0, 150.0));
+/// ```
+pub fn min_engagement_length(
+    major_dia: f64,
+    tpi: u32,
+    external_uts: f64,
+    internal_uts: f64,
+) -> f64 {
+    let ext = calc_uts_extern_thread(
+        major_dia,
+        tpi,
+        &ThreadClass::A2,
+        &RootForm::Un,
+        Some(major_dia),
+    );
+    let int = calc_uts_intern_thread(major_dia, tpi, ThreadClassInternal::B2, Some(major_dia));
+    let n = tpi as f64;
+    let kn_max = int.d1_max();
+    let es_min = ext.d2_min();
+    let ds_min = ext.d_min();
+    let en_max = int.d2_max();
+
+    let at = PI / 4.0 * float::powi(major_dia - 0.9743 / n, 2);
+    let le = 2.0 * at / (PI * kn_max * (0.5 + 0.57735 * n * (es_min - kn_max)));
+    // Shear areas per unit length of engagement.
+    let as_ = PI * n * kn_max * (1.0 / (2.0 * n) + 0.57735 * (es_min - kn_max));
+    let an = PI * n * ds_min * (1.0 / (2.0 * n) + 0.57735 * (ds_min - en_max));
+    let j = as_ * external_uts / (an * internal_uts);
+    le * j.max(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math;
+
+    #[test]
+    fn test_calc_uts_thread_allowance() {
+        let pitch = 1.0 / 28.0;
+        let es = math::round(
+            calc_uts_allowance(0.5, pitch, &ThreadClass::A2, Some(0.4)),
+            6,
+        );
+        assert_eq!(es, 0.00113);
+
+        let pitch = 1.0 / 20.0;
+        let es = math::round(
+            calc_uts_allowance(0.25, pitch, &ThreadClass::A1, Some(0.0125)),
+            6,
+        );
+        assert_eq!(es, 0.000945);
+
+        let es = calc_uts_allowance(0.25, pitch, &ThreadClass::A3, Some(0.0125));
+        assert_eq!(es, 0.0);
+    }
+
+    #[test]
+    fn test_thread_class_from_str() {
+        assert_eq!("1A".parse(), Ok(ThreadClass::A1));
+        assert_eq!("2A".parse(), Ok(ThreadClass::A2));
+        assert_eq!(" 3a ".parse(), Ok(ThreadClass::A3));
+        assert_eq!("4A".parse::<ThreadClass>(), Err(ParseThreadClassError));
+        assert_eq!("2B".parse::<ThreadClass>(), Err(ParseThreadClassError));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_thread_class_display() {
+        for class in [ThreadClass::A1, ThreadClass::A2, ThreadClass::A3] {
+            assert_eq!(class.to_string().parse(), Ok(class));
+        }
+        assert_eq!(ThreadClass::A2.to_string(), "2A");
+    }
+
+    #[test]
+    fn test_parse_thread_spec() {
+        let unified = |major_dia, tpi, series, root, class| ThreadSpec::Unified {
+            major_dia,
+            tpi,
+            series,
+            root,
+            class,
+        };
+        assert_eq!(
+            parse_thread_spec("1/2-13 UNC 2A"),
+            Ok(unified(
+                0.5,
+                13,
+                Some(UnSeries::Unc),
+                RootForm::Un,
+                Some(UnifiedClass::External(ThreadClass::A2))
+            ))
+        );
+        assert_eq!(
+            parse_thread_spec(" 1-1/2-12 unrf-3a "),
+            Ok(unified(
+                1.5,
+                12,
+                Some(UnSeries::Unf),
+                RootForm::Unr,
+                Some(UnifiedClass::External(ThreadClass::A3))
+            ))
+        );
+        assert_eq!(
+            parse_thread_spec("#10-32 UNF 2B"),
+            Ok(unified(
+                0.19,
+                32,
+                Some(UnSeries::Unf),
+                RootForm::Un,
+                Some(UnifiedClass::Internal(ThreadClassInternal::B2))
+            ))
+        );
+        assert_eq!(
+            parse_thread_spec("4-40"),
+            Ok(unified(0.112, 40, None, RootForm::Un, None))
+        );
+        assert_eq!(
+            parse_thread_spec("2\"-12 UN"),
+            Ok(unified(2.0, 12, Some(UnSeries::Un), RootForm::Un, None))
+        );
+        assert_eq!(
+            parse_thread_spec("0.75-16 UNC"),
+            Ok(unified(0.75, 16, Some(UnSeries::Unc), RootForm::Un, None))
+        );
+        assert_eq!(
+            parse_thread_spec("1/2-13-UNC-2A"),
+            parse_thread_spec("1/2-13 UNC 2A")
+        );
+        assert_eq!(
+            parse_thread_spec("1-1/2-12-UNRF-3A"),
+            parse_thread_spec("1-1/2-12 UNRF 3A")
+        );
+        assert_eq!(
+            parse_thread_spec("1/4-20UNC"),
+            Ok(unified(0.25, 20, Some(UnSeries::Unc), RootForm::Un, None))
+        );
+        assert_eq!(
+            parse_thread_spec("1/4-20UNC-2A"),
+            Ok(unified(
+                0.25,
+                20,
+                Some(UnSeries::Unc),
+                RootForm::Un,
+                Some(UnifiedClass::External(ThreadClass::A2))
+            ))
+        );
+        assert_eq!(
+            parse_thread_spec("1-8"),
+            Ok(unified(1.0, 8, None, RootForm::Un, None))
+        );
+        assert!(parse_thread_spec("1/4-20XYZ").is_err());
+        assert!(parse_thread_spec("1/4 20 UNC").is_err());
+
+        assert_eq!(
+            parse_thread_spec("M10x1.5-6g"),
+            Ok(ThreadSpec::Metric {
+                major_dia: 10.0,
+                pitch: 1.5,
+                class: Some(IsoToleranceClass::G6)
+            })
+        );
+        assert_eq!(
+            parse_thread_spec("M6 x 0.75 - 6H"),
+            Ok(ThreadSpec::Metric {
+                major_dia: 6.0,
+                pitch: 0.75,
+                class: Some(IsoToleranceClass::H6)
+            })
+        );
+        assert_eq!(
+            parse_thread_spec("m3x0.5"),
+            Ok(ThreadSpec::Metric {
+                major_dia: 3.0,
+                pitch: 0.5,
+                class: None
+            })
+        );
+
+        for bad in [
+            "",
+            "1/2",
+            "1/2-13 UNC 2A 2A",
+            "1/2-13 2A UNC",
+            "1/2-13.5 UNC",
+            "1/0-13",
+            "#14-20",
+            "1/2-13 UNJC",
+            "M10",
+            "M10x1.5-7g6g",
+            "M10x0-6g",
+        ] {
+            assert_eq!(parse_thread_spec(bad), Err(ParseThreadSpecError), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_standard_thread() {
+        assert_eq!(standard_thread(0.375, UnSeries::Unc), Some((0.375, 16)));
+        assert_eq!(standard_thread(0.375, UnSeries::Unf), Some((0.375, 24)));
+        assert_eq!(standard_thread(0.375, UnSeries::Unef), Some((0.375, 32)));
+        assert_eq!(standard_thread(0.19, UnSeries::Unc), Some((0.19, 24)));
+        assert_eq!(standard_thread(0.1904, UnSeries::Unf), Some((0.19, 32)));
+        assert_eq!(standard_thread(1.75, UnSeries::Unc), Some((1.75, 5)));
+
+        // #0 is fine thread only, and there is no 1-3/4" UNF.
+        assert_eq!(standard_thread(0.06, UnSeries::Unc), None);
+        assert_eq!(standard_thread(1.75, UnSeries::Unf), None);
+        assert_eq!(standard_thread(0.3, UnSeries::Unc), None);
+        assert_eq!(standard_thread(0.5, UnSeries::Un), None);
+
+        // Each table runs from small to large with the pitch never getting finer.
+        for table in [&UNC_SIZES[..], &UNF_SIZES, &UNEF_SIZES] {
+            assert!(table
+                .windows(2)
+                .all(|w| w[0].0 < w[1].0 && w[0].1 >= w[1].1));
+        }
+    }
+
+    #[test]
+    fn test_calc_uts_extern_thread() {
+        let n = calc_uts_extern_thread(0.5, 28, &ThreadClass::A2, &RootForm::Un, None);
+        println!("{:?}", n);
+
+        let n = calc_uts_extern_thread(0.25, 20, &ThreadClass::A2, &RootForm::Un, None);
+        println!("{:?}", n);
+    }
+
+    /// Asserts a calculated diameter is within a tenth (0.0001") of a published value.
+    fn assert_tenth(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= 0.0001,
+            "{actual} is not within 0.0001 of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_calc_uts_extern_major_dia() {
+        // Machinery's Handbook, UNC external threads. The tables use LE = D.
+        let n = calc_uts_extern_thread(0.25, 20, &ThreadClass::A1, &RootForm::Unr, Some(0.25));
+        assert_tenth(n.d_max, 0.2489);
+        assert_tenth(n.d_min, 0.2367);
+
+        let n = calc_uts_extern_thread(0.25, 20, &ThreadClass::A2, &RootForm::Unr, Some(0.25));
+        assert_tenth(n.d_max, 0.2489);
+        assert_tenth(n.d_min, 0.2408);
+
+        let n = calc_uts_extern_thread(0.25, 20, &ThreadClass::A3, &RootForm::Unr, Some(0.25));
+        assert_tenth(n.d_max, 0.2500);
+        assert_tenth(n.d_min, 0.2419);
+
+        let n = calc_uts_extern_thread(0.375, 16, &ThreadClass::A1, &RootForm::Unr, Some(0.375));
+        assert_tenth(n.d_max, 0.3737);
+        assert_tenth(n.d_min, 0.3595);
+
+        let n = calc_uts_extern_thread(0.375, 16, &ThreadClass::A2, &RootForm::Unr, Some(0.375));
+        assert_tenth(n.d_max, 0.3737);
+        assert_tenth(n.d_min, 0.3643);
+    }
+
+    #[test]
+    fn test_thread_form_constants() {
+        assert_eq!(math::round(SQRT3_OVER_2, 9), 0.866025404);
+        assert_eq!(math::round(PITCH_DIA_FACTOR, 6), 0.649519);
+        assert_eq!(math::round(BASIC_MINOR_FACTOR, 6), 1.082532);
+        assert_eq!(math::round(UN_MINOR_FACTOR, 6), 1.299038);
+        assert_eq!(math::round(UNR_MINOR_FACTOR, 6), 1.226869);
+
+        // 1/4-20: H = 0.866025 / 20.
+        let n = calc_uts_extern_thread(0.25, 20, &ThreadClass::A2, &RootForm::Un, Some(0.25));
+        assert_eq!(math::round(n.h(), 7), 0.0433013);
+        assert_eq!(math::round(n.h_as(), 7), 0.0162380);
+        assert_eq!(math::round(n.d2(), 6), 0.217524);
+    }
+
+    #[test]
+    fn test_calc_uts_extern_pitch_dia() {
+        // Machinery's Handbook, 1/4-20 UNC-2A.
+        let n = calc_uts_extern_thread(0.25, 20, &ThreadClass::A2, &RootForm::Unr, Some(0.25));
+        assert_tenth(n.p(), 0.05);
+        assert_tenth(n.es(), 0.0011);
+        assert_tenth(n.d2(), 0.2175);
+        assert_tenth(n.d2_max(), 0.2164);
+        assert_tenth(n.d2_min(), 0.2127);
+        assert_micron(n.d2_max() - n.d2_min(), n.td2());
+
+        // The pitch diameter limits feed straight into the three-wire check.
+        let w = best_wire_size(20);
+        let m_max = three_wire_measurement(n.d2_max(), 20, w, 60.0);
+        let m_min = three_wire_measurement(n.d2_min(), 20, w, 60.0);
+        assert_micron(m_max - m_min, n.td2());
+    }
+
+    #[test]
+    fn test_calc_uts_extern_minor_dia() {
+        let unr = calc_uts_extern_thread(0.25, 20, &ThreadClass::A2, &RootForm::Unr, Some(0.25));
+        let un = calc_uts_extern_thread(0.25, 20, &ThreadClass::A2, &RootForm::Un, Some(0.25));
+        assert!(unr.d1 > un.d1);
+        assert_tenth(unr.d1, 0.1876);
+        // The rounded root raises the minor by H/12 (0.072169P) over the flat root.
+        assert!((unr.d1 - un.d1 - un.h / 12.0).abs() < 1e-12);
+
+        let unr = calc_uts_extern_thread(0.5, 13, &ThreadClass::A2, &RootForm::Unr, Some(0.5));
+        let un = calc_uts_extern_thread(0.5, 13, &ThreadClass::A2, &RootForm::Un, Some(0.5));
+        assert!(unr.d1 > un.d1);
+        assert_tenth(unr.d1, 0.4041);
+    }
+
+    #[test]
+    fn test_calc_uts_length_of_engagement() {
+        // 1/4-20 2A at LE = D = 0.25" (5 pitches). The LE is a length in inches and
+        // enters the base tolerance as T = 0.0015∛D + 0.0015√LE + 0.015∛P² = 0.003731".
+        let n = calc_uts_extern_thread(0.25, 20, &ThreadClass::A2, &RootForm::Unr, Some(0.25));
+        assert_eq!(n.le(), 0.25);
+        assert!((n.t() - 0.003731).abs() < 1e-6);
+        assert_eq!(n.td2(), n.t());
+        assert_tenth(n.d2_min(), 0.2127);
+        assert_eq!(
+            n.es(),
+            calc_uts_allowance(0.25, 0.05, &ThreadClass::A2, Some(n.le()))
+        );
+
+        // A longer engagement only changes the √LE term.
+        let long = calc_uts_extern_thread(0.25, 20, &ThreadClass::A2, &RootForm::Unr, Some(0.45));
+        assert_eq!(long.le(), 0.45);
+        assert!((long.t() - 0.003987).abs() < 1e-6);
+
+        // 3/8-16 2B at LE = D = 0.375" (6 pitches): T = 0.004363".
+        let n = calc_uts_intern_thread(0.375, 16, ThreadClassInternal::B2, Some(0.375));
+        assert_eq!(n.le(), 0.375);
+        assert!((n.t() - 0.004363).abs() < 1e-6);
+        assert_tenth(n.d2_max(), 0.3401);
+
+        // The default is 9 pitches.
+        let n = calc_uts_extern_thread(0.25, 20, &ThreadClass::A2, &RootForm::Unr, None);
+        assert_micron(n.le(), 0.45);
+    }
+
+    #[test]
+    fn test_calc_uts_intern_thread() {
+        // Machinery's Handbook, UNC internal threads, LE = D.
+        let n = calc_uts_intern_thread(0.25, 20, ThreadClassInternal::B2, Some(0.25));
+        assert_tenth(n.d1_min(), 0.1959);
+        assert_tenth(n.d1_max(), 0.2067);
+        assert_tenth(n.d2_min(), 0.2175);
+        assert_tenth(n.d2_max(), 0.2224);
+        assert_eq!(n.d_min(), 0.25);
+
+        // Coarse pitch: the minor tolerance is held to the 0.25P - 0.4P² minimum.
+        let n = calc_uts_intern_thread(0.5, 13, ThreadClassInternal::B2, Some(0.5));
+        assert_tenth(n.d1_min(), 0.4167);
+        assert_tenth(n.d1_max(), 0.4336);
+        assert_tenth(n.d2_min(), 0.4500);
+        assert_tenth(n.d2_max(), 0.4565);
+
+        // 3B uses a smaller minimum and a tighter pitch tolerance.
+        let b3 = calc_uts_intern_thread(0.5, 13, ThreadClassInternal::B3, Some(0.5));
+        assert_tenth(b3.d1_max(), 0.4284);
+        assert!(b3.td2() < n.td2());
+        let b1 = calc_uts_intern_thread(0.5, 13, ThreadClassInternal::B1, Some(0.5));
+        assert_eq!(b1.td2(), 1.5 * n.td2());
+    }
+
+    /// Asserts a calculated metric diameter is within a micron of a published value.
+    fn assert_micron(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= 0.0005,
+            "{actual} is not within 0.0005 of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_calc_iso_extern_thread() {
+        // ISO 965-2 limits for M6×1 6g and M10×1.5 6g.
+        let m6 = calc_iso_extern_thread(6.0, 1.0, IsoToleranceClass::G6, None);
+        assert_micron(m6.d_max(), 5.974);
+        assert_micron(m6.d_min(), 5.794);
+        assert_micron(m6.d2_max(), 5.324);
+        assert_micron(m6.d2_min(), 5.212);
+
+        let m10 = calc_iso_extern_thread(10.0, 1.5, IsoToleranceClass::G6, None);
+        assert_micron(m10.d_max(), 9.968);
+        assert_micron(m10.d_min(), 9.732);
+        assert_micron(m10.d2_max(), 8.994);
+        assert_micron(m10.d2_min(), 8.862);
+
+        // 4h has no allowance and the grade 4 tolerances (Td = 112, Td2 = 71 µm).
+        let m6 = calc_iso_extern_thread(6.0, 1.0, IsoToleranceClass::H4, None);
+        assert_eq!(m6.es(), 0.0);
+        assert_micron(m6.d_min(), 5.888);
+        assert_micron(m6.d2_min(), 5.279);
+
+        // A long engagement moves 6g to 7g6g: the pitch tolerance opens up to 140 µm.
+        let long = calc_iso_extern_thread(6.0, 1.0, IsoToleranceClass::G6, Some(20.0));
+        assert_micron(long.td2(), 0.140);
+        assert_micron(long.td(), 0.180);
+    }
+
+    #[test]
+    fn test_calc_acme_thread() {
+        // Machinery's Handbook, 1/2-10 general purpose Acme.
+        let acme = calc_acme_thread(0.5, 10, AcmeClass::G2);
+        assert_tenth(acme.p(), 0.1);
+        assert_tenth(acme.depth(), 0.06);
+        assert_tenth(acme.flat(), 0.0371);
+        assert_tenth(acme.d_min(), 0.4950);
+        assert_tenth(acme.d1(), 0.3800);
+        assert_tenth(acme.d2(), 0.4500);
+        assert_tenth(acme.d2_max(), 0.4443);
+        assert_tenth(acme.d2_min(), 0.4306);
+
+        // Tighter classes have less allowance and tolerance.
+        let g3 = calc_acme_thread(0.5, 10, AcmeClass::G3);
+        let g4 = calc_acme_thread(0.5, 10, AcmeClass::G4);
+        assert!(g3.d2_max() > acme.d2_max() && g4.d2_max() > g3.d2_max());
+        assert!(g4.d2_max() - g4.d2_min() < g3.d2_max() - g3.d2_min());
+
+        // Coarser than 10 TPI gets the larger clearance.
+        assert_tenth(calc_acme_thread(1.0, 5, AcmeClass::G2).depth(), 0.12);
+    }
+
+    #[test]
+    fn test_calc_npt_thread() {
+        let npt = calc_npt_thread("1/4").unwrap();
+        assert_eq!(npt.tpi(), 18.0);
+        assert_micron(npt.e0(), 0.47739);
+        assert_micron(npt.e1(), 0.49163);
+        assert_tenth(npt.half_angle(), 1.7899);
+        assert_tenth(npt.hand_tight(), 0.2278);
+        assert_tenth(npt.wrench_tight(), 0.3945);
+        assert_eq!(npt.tap_drill().designation, "7/16\"");
+        assert_eq!(npt.tap_drill().dia, 0.4375);
+
+        assert_eq!(calc_npt_thread("1-1/2").unwrap().tpi(), 11.5);
+        assert!(calc_npt_thread("5/16").is_none());
+    }
+
+    #[test]
+    fn test_three_wire_measurement() {
+        // 1/2-13 UNC: basic pitch diameter 0.4500", best wire 0.04441".
+        let w = best_wire_size(13);
+        assert_micron(w, 0.044412);
+        assert_tenth(three_wire_measurement(0.45, 13, w, 60.0), 0.5166);
+
+        // A larger wire rides higher in the groove.
+        assert!(
+            three_wire_measurement(0.45, 13, 0.05, 60.0)
+                > three_wire_measurement(0.45, 13, w, 60.0)
+        );
+
+        // The thread angle generalizes the formula, e.g. 29° Acme.
+        assert_tenth(three_wire_measurement(0.45, 10, 0.0516, 29.0), 0.5144);
+    }
+
+    #[test]
+    fn test_thread_relief() {
+        // 1/2-13 UNC 2A: minor diameter 0.3986".
+        let relief = thread_relief(0.5, 13);
+        let minor = calc_uts_extern_thread(0.5, 13, &ThreadClass::A2, &RootForm::Un, None).d1();
+        assert_tenth(minor, 0.3986);
+        assert_tenth(relief.groove_width(), 0.1154);
+        assert_tenth(relief.groove_dia(), 0.3909);
+        assert!(relief.groove_dia() < minor && minor - relief.groove_dia() < 0.01);
+        assert_tenth(relief.groove_depth(), 0.0546);
+        assert_tenth(relief.chamfer(), 0.0500);
+    }
+
+    #[test]
+    fn test_min_engagement_length() {
+        // Equal strength materials need roughly 0.7 × D with 2A/2B least material limits.
+        let le = min_engagement_length(0.5, 13, 120.0, 120.0);
+        assert_tenth(le, 0.3623);
+        assert!(le > 0.5 * 0.5 && le < 0.8 * 0.5);
+        assert_tenth(min_engagement_length(0.25, 20, 60.0, 60.0), 0.1721);
+
+        // A stronger nut doesn't shorten the engagement the screw needs.
+        assert_eq!(min_engagement_length(0.5, 13, 120.0, 180.0), le);
+
+        // A grade 8 screw (150 ksi) in 6061-T6 (45 ksi).
+        assert_tenth(min_engagement_length(0.5, 13, 150.0, 45.0), 0.8413);
+    }
+
+    #[test]
+    fn test_calc_tap_drill() {
+        // 1/4-20 at 75% is the #7 drill (0.201").
+        assert_eq!(math::round(calc_tap_drill(0.25, 20, 75.0), 3), 0.201);
+        assert!(calc_tap_drill(0.25, 20, 50.0) > calc_tap_drill(0.25, 20, 75.0));
+        assert_eq!(math::round(calc_tap_drill(0.5, 13, 75.0), 4), 0.4251);
+
+        let pct = recommended_thread_pct(Material::StainlessSteel);
+        assert!(pct < recommended_thread_pct(Material::Aluminum));
+        assert!(calc_tap_drill(0.25, 20, pct) > 0.201);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tap_chart() {
+        let chart = tap_chart(UnSeries::Unc, 0.2..=0.5);
+        let sizes = chart
+            .iter()
+            .map(|r| (r.major_dia, r.tpi))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sizes,
+            vec![
+                (0.216, 24),
+                (0.25, 20),
+                (0.3125, 18),
+                (0.375, 16),
+                (0.4375, 14),
+                (0.5, 13)
+            ]
+        );
+
+        // 1/4-20: #7 tap drill and a 9/32" clearance hole.
+        let row = chart[1];
+        assert_tenth(row.tap_dia, 0.2013);
+        assert_eq!(row.tap_drill.unwrap().designation, "#7");
+        assert_eq!(row.clearance_drill.unwrap().designation, "9/32\"");
+
+        // 1/2-13: 27/64" tap drill and a 17/32" clearance hole.
+        assert_eq!(chart[5].tap_drill.unwrap().designation, "27/64\"");
+        assert_eq!(chart[5].clearance_drill.unwrap().designation, "17/32\"");
+
+        // #12 has no row in the clearance hole table.
+        assert!(chart[0].clearance_drill.is_none());
+
+        // Past 1" the sizes are beyond the drill tables.
+        let large = tap_chart(UnSeries::Unc, 1.5..=2.0);
+        assert_eq!(large.len(), 2);
+        assert!(large
+            .iter()
+            .all(|r| r.tap_drill.is_none() && r.clearance_drill.is_none()));
+
+        assert!(tap_chart(UnSeries::Un, 0.0..=2.0).is_empty());
+    }
+
+    #[test]
+    fn test_engagement_from_minor() {
+        // 1/4-20 tapped with the #7 drill: (0.25 - 0.201) × 20 / 1.299 = 75.4%
+        let pct = engagement_from_minor(0.201, 0.25, 20);
+        assert_eq!(math::round(pct, 1), 75.4);
+
+        // Round trip through the tap drill calculation.
+        for (d, tpi) in [(0.25, 20), (0.5, 13), (0.1900, 32), (1.0, 8)] {
+            for pct in [50.0, 65.0, 75.0, 83.0] {
+                let drill = calc_tap_drill(d, tpi, pct);
+                assert_eq!(math::round(engagement_from_minor(drill, d, tpi), 9), pct);
+            }
+        }
+
+        assert_eq!(engagement_from_minor(0.18, 0.25, 20), 100.0);
+        assert_eq!(engagement_from_minor(0.26, 0.25, 20), 0.0);
     }
 }
@@ -0,0 +1,425 @@
+use core::fmt;
+
+use crate::float;
+
+/// The reason a triangle could not be solved.
+///
+/// - Insufficient: Too few values were given to fix the triangle.
+/// - Overdetermined: More values were given than the solver accepts.
+/// - Ambiguous: Two sides and an angle that is not between them allow two different
+///   triangles.
+/// - Invalid: The values do not form a triangle, e.g. a leg longer than the hypotenuse
+///   or angles adding up to 180° or more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriangleError {
+    Insufficient,
+    Overdetermined,
+    Ambiguous,
+    Invalid,
+}
+
+impl fmt::Display for TriangleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            TriangleError::Insufficient => "not enough values to solve the triangle",
+            TriangleError::Overdetermined => "too many values given for the triangle",
+            TriangleError::Ambiguous => "the values fit two different triangles",
+            TriangleError::Invalid => "the values do not form a triangle",
+        };
+        f.write_str(s)
+    }
+}
+
+impl core::error::Error for TriangleError {}
+
+/// Arc cosine in degrees, built on `atan2` so it is available without `std`.
+fn acos_deg(x: f64) -> f64 {
+    let x = x.clamp(-1.0, 1.0);
+    float::atan2(float::sqrt(1.0 - x * x), x).to_degrees()
+}
+
+fn is_positive(v: f64) -> bool {
+    v.is_finite() && v > 0.0
+}
+
+/// A solved right triangle. The angle is measured between the adjacent side and the
+/// hypotenuse; the opposite side faces it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RightTriangle {
+    opposite: f64,
+    adjacent: f64,
+    hypotenuse: f64,
+    angle: f64,
+}
+
+impl RightTriangle {
+    /// Side opposite the angle.
+    pub fn opposite(&self) -> f64 {
+        self.opposite
+    }
+
+    /// Side between the angle and the right angle.
+    pub fn adjacent(&self) -> f64 {
+        self.adjacent
+    }
+
+    /// Side opposite the right angle.
+    pub fn hypotenuse(&self) -> f64 {
+        self.hypotenuse
+    }
+
+    /// The angle in degrees.
+    pub fn angle(&self) -> f64 {
+        self.angle
+    }
+
+    /// The other acute angle in degrees (90° - angle).
+    pub fn complement(&self) -> f64 {
+        90.0 - self.angle
+    }
+}
+
+/// Solves a right triangle from any two of its sides and acute angle.
+///
+/// # Parameters
+/// - opposite: Side opposite the angle.
+/// - adjacent: Side between the angle and the right angle.
+/// - hypotenuse: Side opposite the right angle.
+/// - angle: One acute angle, in degrees.
+///
+/// Exactly two of the values must be given.
+///
+/// # Returns
+/// - `Result<RightTriangle, TriangleError>`: The solved triangle, or an error if fewer
+///   or more than two values are given or they do not form a right triangle.
+///
+/// # Example
+/// ```rust
+/// use smithy::trig::solve_right_triangle;
+/// let t = solve_right_triangle(Some(3.0), Some(4.0), None, None).unwrap();
+/// assert_eq!(t.hypotenuse(), 5.0);
+/// ```
+pub fn solve_right_triangle(
+    opposite: Option<f64>,
+    adjacent: Option<f64>,
+    hypotenuse: Option<f64>,
+    angle: Option<f64>,
+) -> Result<RightTriangle, TriangleError> {
+    let given = [opposite, adjacent, hypotenuse, angle];
+    match given.iter().filter(|v| v.is_some()).count() {
+        0 | 1 => return Err(TriangleError::Insufficient),
+        2 => {}
+        _ => return Err(TriangleError::Overdetermined),
+    }
+    if given.iter().flatten().any(|&v| !is_positive(v)) {
+        return Err(TriangleError::Invalid);
+    }
+
+    let (opposite, adjacent, hypotenuse) = match (opposite, adjacent, hypotenuse, angle) {
+        (o, a, h, Some(ang)) => {
+            if ang >= 90.0 {
+                return Err(TriangleError::Invalid);
+            }
+            let r = ang.to_radians();
+            let (sin, cos) = (float::sin(r), float::cos(r));
+            let h = h.or(o.map(|o| o / sin)).or(a.map(|a| a / cos)).unwrap();
+            (h * sin, h * cos, h)
+        }
+        (Some(o), Some(a), None, None) => (o, a, float::sqrt(o * o + a * a)),
+        (Some(o), None, Some(h), None) if o < h => (o, float::sqrt(h * h - o * o), h),
+        (None, Some(a), Some(h), None) if a < h => (float::sqrt(h * h - a * a), a, h),
+        _ => return Err(TriangleError::Invalid),
+    };
+    Ok(RightTriangle {
+        opposite,
+        adjacent,
+        hypotenuse,
+        angle: float::atan2(opposite, adjacent).to_degrees(),
+    })
+}
+
+/// A solved triangle. Side `a` is opposite angle `alpha`, `b` opposite `beta` and `c`
+/// opposite `gamma`. Angles are in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    sides: [f64; 3],
+    angles: [f64; 3],
+}
+
+impl Triangle {
+    /// Side a, opposite angle alpha.
+    pub fn a(&self) -> f64 {
+        self.sides[0]
+    }
+
+    /// Side b, opposite angle beta.
+    pub fn b(&self) -> f64 {
+        self.sides[1]
+    }
+
+    /// Side c, opposite angle gamma.
+    pub fn c(&self) -> f64 {
+        self.sides[2]
+    }
+
+    /// Angle alpha, opposite side a.
+    pub fn alpha(&self) -> f64 {
+        self.angles[0]
+    }
+
+    /// Angle beta, opposite side b.
+    pub fn beta(&self) -> f64 {
+        self.angles[1]
+    }
+
+    /// Angle gamma, opposite side c.
+    pub fn gamma(&self) -> f64 {
+        self.angles[2]
+    }
+}
+
+/// Solves any triangle from three of its sides and angles, with at least one side.
+///
+/// The cases are handled with the laws of sines and cosines:
+///
+/// ```markdown
+/// SSS, SAS:  c² = a² + b² - 2ab × cos(γ)
+/// ASA, AAS:  a / sin(α) = b / sin(β) = c / sin(γ)
+/// ```
+///
+/// Two sides and an angle that is not between them (SSA) is only solved when it gives a
+/// single triangle, i.e. when the side opposite the angle is at least as long as the
+/// other side or the triangle is right-angled.
+///
+/// # Parameters
+/// - a, b, c: The sides.
+/// - alpha, beta, gamma: The angles opposite `a`, `b` and `c`, in degrees.
+///
+/// Exactly three of the values must be given, and at least one of them must be a side.
+///
+/// # Returns
+/// - `Result<Triangle, TriangleError>`: The solved triangle, or an error if the values
+///   do not fix a single triangle.
+///
+/// # Example
+/// ```rust
+/// use smithy::trig::solve_oblique;
+/// let t = solve_oblique(Some(5.0), Some(7.0), None, None, None, Some(60.0)).unwrap();
+/// assert!((t.c() - 39.0_f64.sqrt()).abs() < 1e-9);
+/// ```
+pub fn solve_oblique(
+    a: Option<f64>,
+    b: Option<f64>,
+    c: Option<f64>,
+    alpha: Option<f64>,
+    beta: Option<f64>,
+    gamma: Option<f64>,
+) -> Result<Triangle, TriangleError> {
+    let mut sides = [a, b, c];
+    let mut angles = [alpha, beta, gamma];
+    let num_sides = sides.iter().flatten().count();
+    let num_angles = angles.iter().flatten().count();
+    match num_sides + num_angles {
+        0..=2 => return Err(TriangleError::Insufficient),
+        3 => {}
+        _ => return Err(TriangleError::Overdetermined),
+    }
+    if num_sides == 0 {
+        return Err(TriangleError::Insufficient);
+    }
+    if sides
+        .iter()
+        .chain(angles.iter())
+        .flatten()
+        .any(|&v| !is_positive(v))
+    {
+        return Err(TriangleError::Invalid);
+    }
+
+    let sin = |deg: f64| float::sin(deg.to_radians());
+    match num_angles {
+        // SSS.
+        0 => {}
+        // SAS or SSA.
+        1 => {
+            let i = angles.iter().position(|a| a.is_some()).unwrap();
+            let ang = angles[i].unwrap();
+            if ang >= 180.0 {
+                return Err(TriangleError::Invalid);
+            }
+            match sides[i] {
+                // SAS: the known angle is between the known sides.
+                None => {
+                    let (x, y) = (sides[(i + 1) % 3].unwrap(), sides[(i + 2) % 3].unwrap());
+                    let cos = float::cos(ang.to_radians());
+                    sides[i] = Some(float::sqrt(x * x + y * y - 2.0 * x * y * cos));
+                }
+                // SSA: the known angle faces a known side.
+                Some(x) => {
+                    let j = if sides[(i + 1) % 3].is_some() {
+                        (i + 1) % 3
+                    } else {
+                        (i + 2) % 3
+                    };
+                    let y = sides[j].unwrap();
+                    // A right or obtuse angle must face the longest side.
+                    if ang >= 90.0 && x <= y {
+                        return Err(TriangleError::Invalid);
+                    }
+                    let sin_j = y * sin(ang) / x;
+                    if sin_j > 1.0 + 1e-12 {
+                        return Err(TriangleError::Invalid);
+                    }
+                    if x < y && sin_j < 1.0 - 1e-12 {
+                        return Err(TriangleError::Ambiguous);
+                    }
+                    // The angle opposite the shorter (or equal) side is acute.
+                    let ang_j = float::atan2(sin_j, float::sqrt((1.0 - sin_j * sin_j).max(0.0)));
+                    angles[j] = Some(ang_j.to_degrees());
+                }
+            }
+        }
+        // ASA or AAS.
+        _ => {}
+    }
+
+    if angles.iter().flatten().count() == 2 {
+        let sum: f64 = angles.iter().flatten().sum();
+        if sum >= 180.0 {
+            return Err(TriangleError::Invalid);
+        }
+        let k = angles.iter().position(|a| a.is_none()).unwrap();
+        angles[k] = Some(180.0 - sum);
+        // Law of sines from a known side.
+        let i = sides.iter().position(|s| s.is_some()).unwrap();
+        let ratio = sides[i].unwrap() / sin(angles[i].unwrap());
+        for j in 0..3 {
+            sides[j] = sides[j].or(Some(ratio * sin(angles[j].unwrap())));
+        }
+    }
+
+    let [a, b, c] = sides.map(|s| s.unwrap());
+    if a + b <= c || a + c <= b || b + c <= a {
+        return Err(TriangleError::Invalid);
+    }
+    let alpha = acos_deg((b * b + c * c - a * a) / (2.0 * b * c));
+    let beta = acos_deg((a * a + c * c - b * b) / (2.0 * a * c));
+    Ok(Triangle {
+        sides: [a, b, c],
+        angles: [alpha, beta, 180.0 - alpha - beta],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math;
+
+    #[test]
+    fn test_solve_right_triangle() {
+        let expected = (3.0, 4.0, 5.0, 36.8699);
+        let check = |t: RightTriangle| {
+            let actual = (
+                math::round(t.opposite(), 9),
+                math::round(t.adjacent(), 9),
+                math::round(t.hypotenuse(), 9),
+                math::round(t.angle(), 4),
+            );
+            assert_eq!(actual, expected);
+        };
+        check(solve_right_triangle(Some(3.0), Some(4.0), None, None).unwrap());
+        check(solve_right_triangle(Some(3.0), None, Some(5.0), None).unwrap());
+        check(solve_right_triangle(None, Some(4.0), Some(5.0), None).unwrap());
+        let angle = float::atan2(3.0, 4.0).to_degrees();
+        check(solve_right_triangle(Some(3.0), None, None, Some(angle)).unwrap());
+        check(solve_right_triangle(None, Some(4.0), None, Some(angle)).unwrap());
+        check(solve_right_triangle(None, None, Some(5.0), Some(angle)).unwrap());
+
+        let t = solve_right_triangle(Some(3.0), Some(4.0), None, None).unwrap();
+        assert_eq!(math::round(t.complement(), 4), 53.1301);
+
+        assert_eq!(
+            solve_right_triangle(Some(3.0), None, None, None),
+            Err(TriangleError::Insufficient)
+        );
+        assert_eq!(
+            solve_right_triangle(Some(3.0), Some(4.0), Some(5.0), None),
+            Err(TriangleError::Overdetermined)
+        );
+        assert_eq!(
+            solve_right_triangle(Some(6.0), None, Some(5.0), None),
+            Err(TriangleError::Invalid)
+        );
+        assert_eq!(
+            solve_right_triangle(Some(3.0), None, None, Some(90.0)),
+            Err(TriangleError::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_solve_oblique() {
+        let round = |t: Triangle| {
+            [t.a(), t.b(), t.c(), t.alpha(), t.beta(), t.gamma()].map(|v| math::round(v, 4))
+        };
+
+        // SAS: a = 5, b = 7, included angle 60°: c = √39.
+        let sas = solve_oblique(Some(5.0), Some(7.0), None, None, None, Some(60.0)).unwrap();
+        assert_eq!(round(sas), [5.0, 7.0, 6.245, 43.8979, 76.1021, 60.0]);
+
+        // The same triangle from the other cases.
+        let sss = solve_oblique(Some(5.0), Some(7.0), Some(sas.c()), None, None, None).unwrap();
+        assert_eq!(round(sss), round(sas));
+        let asa = solve_oblique(
+            None,
+            None,
+            Some(sas.c()),
+            Some(sas.alpha()),
+            Some(sas.beta()),
+            None,
+        );
+        assert_eq!(round(asa.unwrap()), round(sas));
+        let aas = solve_oblique(Some(5.0), None, None, Some(sas.alpha()), None, Some(60.0));
+        assert_eq!(round(aas.unwrap()), round(sas));
+        // SSA with the angle facing the longer side is unique.
+        let ssa = solve_oblique(None, Some(7.0), Some(sas.c()), None, Some(sas.beta()), None);
+        assert_eq!(round(ssa.unwrap()), round(sas));
+
+        // 3-4-5 is right-angled.
+        let t = solve_oblique(Some(3.0), Some(4.0), Some(5.0), None, None, None).unwrap();
+        assert_eq!(math::round(t.gamma(), 9), 90.0);
+
+        // SSA with the angle facing the shorter side has two solutions.
+        assert_eq!(
+            solve_oblique(Some(6.0), Some(8.0), None, Some(40.0), None, None),
+            Err(TriangleError::Ambiguous)
+        );
+        assert_eq!(
+            solve_oblique(Some(1.0), Some(8.0), None, Some(40.0), None, None),
+            Err(TriangleError::Invalid)
+        );
+        // An obtuse or right angle facing the shorter side has no solution.
+        assert_eq!(
+            solve_oblique(Some(6.0), Some(8.0), None, Some(120.0), None, None),
+            Err(TriangleError::Invalid)
+        );
+        assert_eq!(
+            solve_oblique(None, Some(5.0), Some(5.0), None, Some(90.0), None),
+            Err(TriangleError::Invalid)
+        );
+        // Facing the longer side it is unique.
+        let t = solve_oblique(Some(8.0), Some(6.0), None, Some(120.0), None, None).unwrap();
+        assert_eq!(math::round(t.alpha() + t.beta() + t.gamma(), 9), 180.0);
+        assert_eq!(
+            solve_oblique(Some(1.0), Some(2.0), Some(4.0), None, None, None),
+            Err(TriangleError::Invalid)
+        );
+        assert_eq!(
+            solve_oblique(None, None, None, Some(60.0), Some(60.0), Some(60.0)),
+            Err(TriangleError::Insufficient)
+        );
+        assert_eq!(
+            solve_oblique(Some(1.0), None, None, Some(100.0), Some(90.0), None),
+            Err(TriangleError::Invalid)
+        );
+    }
+}
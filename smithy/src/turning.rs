@@ -0,0 +1,621 @@
+use core::f64::consts::PI;
+
+use crate::float;
+
+/// Estimates the tip deflection of a boring bar under cutting load.
+///
+/// The bar is treated as a round cantilever clamped at the holder and loaded at the
+/// cutting edge, so the deflection is:
+///
+/// ```markdown
+/// δ = F × L³ / (3 × E × I),   I = π × d⁴ / 64
+/// ```
+///
+/// All inputs must use one consistent unit system (e.g. inches, lbf and psi, or
+/// mm, N and N/mm²).
+///
+/// # Parameters
+/// - length: Overhang of the bar from the holder to the cutting edge (L).
+/// - dia: Diameter of the bar shank (d).
+/// - force: Cutting force acting on the tip of the bar (F).
+/// - youngs_modulus: Young's modulus of the bar material (E), e.g. ~30e6 psi for steel
+///   or ~90e6 psi for carbide.
+///
+/// # Returns
+/// - `f64`: Deflection of the bar tip, in the length unit of the inputs.
+///
+/// # Example
+/// ```rust
+/// use smithy::turning::bar_deflection;
+/// let steel = bar_deflection(2.0, 0.5, 100.0, 30e6);
+/// let carbide = bar_deflection(2.0, 0.5, 100.0, 90e6);
+/// assert!(carbide < steel);
+/// ```
+pub fn bar_deflection(length: f64, dia: f64, force: f64, youngs_modulus: f64) -> f64 {
+    let i = PI * float::powi(dia, 4) / 64.0;
+    force * float::powi(length, 3) / (3.0 * youngs_modulus * i)
+}
+
+/// Boring bar shank materials.
+///
+/// - Steel: Solid steel bars, E ≈ 30e6 psi (207 GPa).
+/// - Carbide: Solid carbide bars, about three times as stiff, E ≈ 90e6 psi (620 GPa).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarMaterial {
+    Steel,
+    Carbide,
+}
+
+/// Returns the recommended maximum overhang of a boring bar, as a multiple of its
+/// diameter.
+///
+/// Past this length to diameter (L/D) ratio a bar is likely to chatter: about 4 × D for
+/// steel and 6 × D for carbide. Tuned, damped bars reach 10 × D or more.
+///
+/// # Parameters
+/// - material: The bar shank material.
+///
+/// # Returns
+/// - `f64`: The maximum L/D ratio.
+///
+/// # Example
+/// ```rust
+/// use smithy::turning::{max_overhang_ratio, BarMaterial};
+/// let max_overhang = 0.5 * max_overhang_ratio(BarMaterial::Steel);
+/// assert_eq!(max_overhang, 2.0);
+/// ```
+pub fn max_overhang_ratio(material: BarMaterial) -> f64 {
+    match material {
+        BarMaterial::Steel => 4.0,
+        BarMaterial::Carbide => 6.0,
+    }
+}
+
+/// Deflection and overhang of a boring bar setup, see `check_boring_bar`.
+#[derive(Debug, Default)]
+pub struct BoringBarCheck {
+    deflection: f64,
+    overhang_ratio: f64,
+    within_ratio: bool,
+}
+
+impl BoringBarCheck {
+    /// Deflection of the bar tip, from `bar_deflection`.
+    pub fn deflection(&self) -> f64 {
+        self.deflection
+    }
+
+    /// Overhang as a multiple of the bar diameter (L/D).
+    pub fn overhang_ratio(&self) -> f64 {
+        self.overhang_ratio
+    }
+
+    /// Whether the overhang is within `max_overhang_ratio` for the bar material.
+    pub fn within_ratio(&self) -> bool {
+        self.within_ratio
+    }
+}
+
+/// Checks a boring bar setup for deflection and overhang.
+///
+/// # Parameters
+/// - length: Overhang of the bar from the holder to the cutting edge (L).
+/// - dia: Diameter of the bar shank (d).
+/// - force: Cutting force acting on the tip of the bar (F).
+/// - youngs_modulus: Young's modulus of the bar material (E), in units consistent with
+///   the other inputs, see `bar_deflection`.
+/// - material: The bar shank material, which sets the recommended overhang.
+///
+/// # Returns
+/// - `BoringBarCheck`: The tip deflection, the L/D ratio and whether it is within the
+///   recommended limit.
+///
+/// # Example
+/// ```rust
+/// use smithy::turning::{check_boring_bar, BarMaterial};
+/// let check = check_boring_bar(3.0, 0.5, 100.0, 30e6, BarMaterial::Steel);
+/// assert!(!check.within_ratio());
+/// ```
+pub fn check_boring_bar(
+    length: f64,
+    dia: f64,
+    force: f64,
+    youngs_modulus: f64,
+    material: BarMaterial,
+) -> BoringBarCheck {
+    let overhang_ratio = length / dia;
+    BoringBarCheck {
+        deflection: bar_deflection(length, dia, force, youngs_modulus),
+        overhang_ratio,
+        within_ratio: overhang_ratio <= max_overhang_ratio(material),
+    }
+}
+
+/// Recommends a spindle speed for knurling.
+///
+/// Knurls form the surface rather than cut it, and running them at turning speed causes
+/// chatter, double tracking and rapid wear. A quarter of the turning speed for the same
+/// diameter and material is a good starting point.
+///
+/// # Parameters
+/// - base_turning_rpm: Spindle speed that would be used to turn the same diameter.
+///
+/// # Returns
+/// - `f64`: Recommended knurling RPM.
+///
+/// # Example
+/// ```rust
+/// use smithy::turning::knurl_rpm;
+/// assert_eq!(knurl_rpm(800.0), 200.0);
+/// ```
+pub fn knurl_rpm(base_turning_rpm: f64) -> f64 {
+    base_turning_rpm / 4.0
+}
+
+/// Calculates the axial feed rate for a straight knurl.
+///
+/// The carriage advances one axial pitch of the knurl per revolution of the work, so the
+/// feed rate is `rpm / tpi_axial`.
+///
+/// # Parameters
+/// - tpi_axial: Axial pitch of the knurl, in teeth per inch.
+/// - rpm: Spindle speed used for knurling (see `knurl_rpm`).
+///
+/// # Returns
+/// - `f64`: Feed rate in inches per minute.
+///
+/// # Example
+/// ```rust
+/// use smithy::turning::knurl_feed;
+/// assert_eq!(knurl_feed(20.0, 200.0), 10.0);
+/// ```
+pub fn knurl_feed(tpi_axial: f64, rpm: f64) -> f64 {
+    rpm / tpi_axial
+}
+
+/// A knurling blank sized so the knurl tracks cleanly. Diameters are in inches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KnurlBlank {
+    dia: f64,
+    teeth: u32,
+    turning_dia: f64,
+}
+
+impl KnurlBlank {
+    /// Tracking diameter, whose circumference is a whole number of knurl pitches.
+    pub fn dia(&self) -> f64 {
+        self.dia
+    }
+
+    /// Number of knurl teeth around the circumference.
+    pub fn teeth(&self) -> u32 {
+        self.teeth
+    }
+
+    /// Diameter to turn before knurling, allowing for the material the knurl raises.
+    pub fn turning_dia(&self) -> f64 {
+        self.turning_dia
+    }
+}
+
+/// Sizes a blank so a knurl divides its circumference evenly.
+///
+/// If the circumference is not a whole number of knurl pitches the teeth fail to land in
+/// their own tracks on the second revolution and the pattern double tracks. The target
+/// diameter is snapped to the nearest diameter that holds a whole number of teeth:
+///
+/// ```markdown
+/// teeth = round(π × target / P),   dia = teeth × P / π
+/// ```
+///
+/// Knurling displaces material outward, raising the diameter by roughly half a pitch, so
+/// the blank is turned that much under the tracking diameter.
+///
+/// # Parameters
+/// - target_dia: Desired diameter of the knurled part, in inches.
+/// - knurl_tpi: Circular pitch of the knurl, in teeth per inch.
+///
+/// # Returns
+/// - `KnurlBlank`: The tracking diameter, tooth count and turning diameter.
+///
+/// # Example
+/// ```rust
+/// use smithy::turning::knurl_blank_diameter;
+/// let blank = knurl_blank_diameter(0.5, 20);
+/// assert_eq!(blank.teeth(), 31);
+/// assert!((blank.dia() - 0.4934).abs() < 0.0001);
+/// ```
+pub fn knurl_blank_diameter(target_dia: f64, knurl_tpi: u32) -> KnurlBlank {
+    let p = 1.0 / knurl_tpi as f64;
+    let teeth = float::round(PI * target_dia / p).max(1.0) as u32;
+    let dia = teeth as f64 * p / PI;
+    KnurlBlank {
+        dia,
+        teeth,
+        turning_dia: dia - 0.5 * p,
+    }
+}
+
+/// Calculates a constant-chip-area infeed schedule for single-point threading.
+///
+/// With radial infeed the chip widens as the tool goes deeper, so taking equal depth
+/// increments overloads the insert on the last passes. Keeping the chip cross-section
+/// constant gives cumulative depths of:
+///
+/// ```markdown
+/// depth(i) = first_pass × √i
+/// ```
+///
+/// The number of passes follows from the first pass; the last pass is capped so it ends
+/// exactly at `thread_depth`.
+///
+/// # Parameters
+/// - thread_depth: Total radial depth of the thread.
+/// - first_pass: Radial depth of the first pass.
+///
+/// # Returns
+/// - `Vec<f64>`: Cumulative infeed depth after each pass. Empty if either input is not
+///   a positive, finite number.
+///
+/// # Example
+/// ```rust
+/// use smithy::turning::threading_infeed_constant_area;
+/// let passes = threading_infeed_constant_area(0.04, 0.01);
+/// assert_eq!(passes.len(), 16);
+/// assert_eq!(passes[15], 0.04);
+/// ```
+#[cfg(feature = "std")]
+pub fn threading_infeed_constant_area(thread_depth: f64, first_pass: f64) -> Vec<f64> {
+    let mut depths = Vec::new();
+    let valid = |v: f64| v.is_finite() && v > 0.0;
+    if !valid(thread_depth) || !valid(first_pass) {
+        return depths;
+    }
+    for i in 1.. {
+        let depth = first_pass * float::sqrt(i as f64);
+        // Allow for float error so an exact final pass isn't followed by a sliver.
+        if depth >= thread_depth - 1e-9 {
+            depths.push(thread_depth);
+            break;
+        }
+        depths.push(depth);
+    }
+    depths
+}
+
+/// How the infeed of a single-point threading cycle is spread over the passes.
+///
+/// - Constant: Equal radial depth on every pass. The chip grows wider with depth, so
+///   the last passes are the heaviest.
+/// - ConstantVolume: Cumulative depth proportional to √pass, keeping the chip
+///   cross-section roughly equal on every pass.
+/// - Degression: The common insert-maker schedule: cumulative depth ∝ √(pass - 1),
+///   with the first pass reduced to √0.3 of the second so the insert enters gently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InfeedMethod {
+    Constant,
+    ConstantVolume,
+    Degression,
+}
+
+/// Calculates the infeed schedule for a fixed number of single-point threading passes.
+///
+/// With `n` passes and thread depth `ap`, the cumulative depth after pass `x` is:
+///
+/// ```markdown
+/// Constant:        ap × x / n
+/// ConstantVolume:  ap × √(x / n)
+/// Degression:      ap × √(φ / (n - 1)),   φ = 0.3 for x = 1, otherwise x - 1
+/// ```
+///
+/// `ConstantVolume` is the schedule of `threading_infeed_constant_area` with a first pass
+/// of `ap / √n`.
+///
+/// The thread depth can be taken from a thread calculation, e.g. half the difference
+/// between the major and minor diameters of `calc_uts_extern_thread`, or
+/// `calc_acme_thread(..).depth()`. See `compound_infeed` to convert the depths to
+/// compound dial readings.
+///
+/// # Parameters
+/// - thread_depth: Total radial depth of the thread.
+/// - num_passes: Number of passes.
+/// - method: How the depth is spread over the passes.
+///
+/// # Returns
+/// - `Vec<f64>`: Cumulative radial infeed after each pass, ending at `thread_depth`.
+///   Empty if there are no passes or the depth is not positive.
+///
+/// # Example
+/// ```rust
+/// use smithy::turning::{thread_pass_schedule, InfeedMethod};
+/// let passes = thread_pass_schedule(0.04, 4, InfeedMethod::ConstantVolume);
+/// assert_eq!(passes.len(), 4);
+/// assert!((passes[1] - 0.04 * 0.5_f64.sqrt()).abs() < 1e-12);
+/// assert_eq!(passes[3], 0.04);
+/// ```
+#[cfg(feature = "std")]
+pub fn thread_pass_schedule(thread_depth: f64, num_passes: u32, method: InfeedMethod) -> Vec<f64> {
+    if num_passes == 0 || thread_depth.is_nan() || thread_depth <= 0.0 {
+        return Vec::new();
+    }
+    let n = num_passes as f64;
+    let depth_at: fn(f64, f64, f64) -> f64 = match method {
+        InfeedMethod::Constant => |ap, x, n| ap * x / n,
+        InfeedMethod::ConstantVolume => {
+            return threading_infeed_constant_area(thread_depth, thread_depth / float::sqrt(n));
+        }
+        InfeedMethod::Degression => |ap, x, n| {
+            let phi = if x == 1.0 { 0.3 } else { x - 1.0 };
+            ap * float::sqrt(phi / (n - 1.0))
+        },
+    };
+    (1..=num_passes)
+        .map(|x| {
+            if x == num_passes {
+                return thread_depth;
+            }
+            depth_at(thread_depth, x as f64, n)
+        })
+        .collect()
+}
+
+/// Converts a radial threading depth to travel of the compound rest.
+///
+/// With the compound set over at an angle from the cross-slide (29.5° for 60° threads,
+/// so the tool shaves the leading flank), its feed screw moves the tool along that angle
+/// and only part of the travel is radial:
+///
+/// ```markdown
+/// compound travel = radial depth / cos(compound angle)
+/// ```
+///
+/// # Parameters
+/// - radial_depth: Radial depth to reach, e.g. one entry of `thread_pass_schedule`.
+/// - compound_angle_deg: Angle of the compound from the cross-slide, in degrees.
+///
+/// # Returns
+/// - `f64`: Distance to feed the compound, i.e. the compound dial reading. Map it over a
+///   pass schedule to get the dial reading for every pass.
+///
+/// # Example
+/// ```rust
+/// use smithy::turning::compound_infeed;
+/// assert!((compound_infeed(0.0406, 29.5) - 0.0466).abs() < 0.0001);
+/// ```
+pub fn compound_infeed(radial_depth: f64, compound_angle_deg: f64) -> f64 {
+    radial_depth / float::cos(compound_angle_deg.to_radians())
+}
+
+/// Which roughness value `theoretical_ra` calculates.
+///
+/// - Ra: Arithmetic mean roughness, the value usually called out on drawings.
+/// - Rmax: Peak-to-valley height of the feed marks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoughnessMetric {
+    Ra,
+    Rmax,
+}
+
+/// Estimates the theoretical surface roughness left by a turning tool.
+///
+/// The tool nose leaves a scallop every revolution, so the roughness depends only on the
+/// feed and nose radius:
+///
+/// ```markdown
+/// Ra   = f² / (31.2 × r)
+/// Rmax = f² / (8 × r)
+/// ```
+///
+/// Real finishes are somewhat rougher because of tool wear, built-up edge and vibration.
+///
+/// # Parameters
+/// - feed: Feed per revolution (f).
+/// - nose_radius: Nose radius of the tool (r), in the same unit as the feed.
+/// - metric: Whether to return Ra or Rmax.
+///
+/// # Returns
+/// - `f64`: The roughness in the unit of the inputs. Multiply by 1,000 for µm from mm,
+///   or by 1,000,000 for µin from inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::turning::{theoretical_ra, RoughnessMetric};
+/// let ra = theoretical_ra(0.2, 0.8, RoughnessMetric::Ra) * 1000.0;
+/// assert!((ra - 1.6).abs() < 0.01);
+/// ```
+pub fn theoretical_ra(feed: f64, nose_radius: f64, metric: RoughnessMetric) -> f64 {
+    let k = match metric {
+        RoughnessMetric::Ra => 31.2,
+        RoughnessMetric::Rmax => 8.0,
+    };
+    feed * feed / (k * nose_radius)
+}
+
+/// Calculates the feed per revolution that gives a target theoretical Ra, the inverse of
+/// `theoretical_ra`.
+///
+/// ```markdown
+/// f = √(Ra × 31.2 × r)
+/// ```
+///
+/// # Parameters
+/// - target_ra: The required Ra, in the unit of the nose radius (e.g. 0.0016 mm for
+///   1.6 µm).
+/// - nose_radius: Nose radius of the tool (r).
+///
+/// # Returns
+/// - `f64`: The largest feed per revolution that meets the finish in theory. Use a
+///   little less in practice.
+///
+/// # Example
+/// ```rust
+/// use smithy::turning::feed_for_ra;
+/// assert!((feed_for_ra(0.0016, 0.8) - 0.1998).abs() < 0.0001);
+/// ```
+pub fn feed_for_ra(target_ra: f64, nose_radius: f64) -> f64 {
+    float::sqrt(target_ra * 31.2 * nose_radius)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math;
+
+    #[test]
+    fn test_knurl_blank_diameter() {
+        // 20 TPI diamond knurl on a 0.5" target: π × 0.5 / 0.05 = 31.4 teeth.
+        let blank = knurl_blank_diameter(0.5, 20);
+        assert_eq!(blank.teeth(), 31);
+        assert_eq!(math::round(blank.dia(), 4), 0.4934);
+        assert_eq!(math::round(blank.turning_dia(), 4), 0.4684);
+        assert_eq!(math::round(PI * blank.dia() * 20.0, 9), 31.0);
+
+        // A diameter that already tracks is left alone.
+        let exact = 40.0 / 20.0 / PI;
+        assert_eq!(
+            math::round(knurl_blank_diameter(exact, 20).dia(), 9),
+            math::round(exact, 9)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_thread_pass_schedule() {
+        let increments = |passes: &[f64]| {
+            let mut prev = 0.0;
+            passes
+                .iter()
+                .map(|&d| {
+                    let inc = d - prev;
+                    prev = d;
+                    inc
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let constant = thread_pass_schedule(0.04, 4, InfeedMethod::Constant);
+        assert_eq!(constant, vec![0.01, 0.02, 0.03, 0.04]);
+
+        let volume = thread_pass_schedule(0.0406, 8, InfeedMethod::ConstantVolume);
+        assert_eq!(volume.len(), 8);
+        assert_eq!(volume[7], 0.0406);
+        let inc = increments(&volume);
+        assert!(inc.windows(2).all(|w| w[1] < w[0]));
+        for (x, d) in volume.iter().enumerate() {
+            let expected = 0.0406 * ((x + 1) as f64 / 8.0).sqrt();
+            assert!((d - expected).abs() < 1e-12);
+        }
+        assert_eq!(
+            volume,
+            threading_infeed_constant_area(0.0406, 0.0406 / 8.0_f64.sqrt())
+        );
+
+        let degression = thread_pass_schedule(0.0406, 8, InfeedMethod::Degression);
+        assert_eq!(degression[7], 0.0406);
+        let inc = increments(&degression);
+        // A lighter first pass than constant volume, then decreasing increments.
+        assert!(inc[0] < volume[0]);
+        assert!(inc.windows(2).all(|w| w[1] < w[0]));
+
+        assert_eq!(
+            thread_pass_schedule(0.04, 1, InfeedMethod::Degression),
+            vec![0.04]
+        );
+        assert!(thread_pass_schedule(0.04, 0, InfeedMethod::Constant).is_empty());
+        assert!(thread_pass_schedule(0.0, 5, InfeedMethod::Constant).is_empty());
+    }
+
+    #[test]
+    fn test_compound_infeed() {
+        // At 29.5° the compound travels 1 / cos(29.5°) = 1.1490 times the radial depth.
+        let travel = compound_infeed(0.0406, 29.5);
+        assert!(travel > 0.0406);
+        assert_eq!(math::round(travel / 0.0406, 4), 1.149);
+        assert_eq!(math::round(travel, 4), 0.0466);
+
+        // Square to the work the compound feeds straight in.
+        assert_eq!(compound_infeed(0.0406, 0.0), 0.0406);
+    }
+
+    #[test]
+    fn test_theoretical_ra() {
+        // 0.2 mm/rev with a 0.8 mm nose: Ra 1.6 µm, Rmax 6.25 µm.
+        let ra = theoretical_ra(0.2, 0.8, RoughnessMetric::Ra);
+        assert_eq!(math::round(ra * 1000.0, 4), 1.6026);
+        let rmax = theoretical_ra(0.2, 0.8, RoughnessMetric::Rmax);
+        assert_eq!(math::round(rmax * 1000.0, 4), 6.25);
+
+        // 0.004 ipr with a 1/32" nose: about 16 µin.
+        let ra = theoretical_ra(0.004, 1.0 / 32.0, RoughnessMetric::Ra);
+        assert_eq!(math::round(ra * 1e6, 1), 16.4);
+    }
+
+    #[test]
+    fn test_feed_for_ra() {
+        assert_eq!(math::round(feed_for_ra(0.0016, 0.8), 4), 0.1998);
+        let ra = theoretical_ra(0.15, 0.4, RoughnessMetric::Ra);
+        assert_eq!(math::round(feed_for_ra(ra, 0.4), 9), 0.15);
+    }
+
+    #[test]
+    fn test_bar_deflection() {
+        // 1/2" steel bar, 2" overhang, 100 lbf: I = 0.003068 in⁴, δ = 800 / 276117
+        let d = bar_deflection(2.0, 0.5, 100.0, 30e6);
+        assert_eq!(math::round(d, 6), 0.002897);
+
+        // Doubling the overhang increases deflection eightfold.
+        let d2 = bar_deflection(4.0, 0.5, 100.0, 30e6);
+        assert_eq!(math::round(d2 / d, 6), 8.0);
+    }
+
+    #[test]
+    fn test_check_boring_bar() {
+        // The same 1/2" steel bar at 2" is right at its 4 × D limit.
+        let check = check_boring_bar(2.0, 0.5, 100.0, 30e6, BarMaterial::Steel);
+        assert_eq!(math::round(check.deflection(), 6), 0.002897);
+        assert_eq!(check.overhang_ratio(), 4.0);
+        assert!(check.within_ratio());
+
+        // At 2.5" it needs a carbide bar.
+        let steel = check_boring_bar(2.5, 0.5, 100.0, 30e6, BarMaterial::Steel);
+        assert!(!steel.within_ratio());
+        let carbide = check_boring_bar(2.5, 0.5, 100.0, 90e6, BarMaterial::Carbide);
+        assert!(carbide.within_ratio());
+        assert!(carbide.deflection() < steel.deflection());
+    }
+
+    #[test]
+    fn test_knurl_rpm() {
+        let turning_rpm = 764.0;
+        let rpm = knurl_rpm(turning_rpm);
+        assert!(rpm <= turning_rpm / 3.0);
+        assert_eq!(rpm, 191.0);
+    }
+
+    #[test]
+    fn test_knurl_feed() {
+        assert_eq!(knurl_feed(25.0, 191.0), 7.64);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_threading_infeed_constant_area() {
+        // 1/4-20 external thread depth, 0.010" first pass
+        let thread_depth = 0.61343 / 20.0;
+        let passes = threading_infeed_constant_area(thread_depth, 0.01);
+        assert_eq!(passes.len(), 10);
+        assert_eq!(passes[0], 0.01);
+        assert_eq!(math::round(passes[3], 6), 0.02);
+        assert!(passes.windows(2).all(|w| w[1] > w[0]));
+        assert_eq!(passes[9], thread_depth);
+
+        // Increments shrink as the chip widens.
+        assert!(passes[1] - passes[0] > passes[8] - passes[7]);
+
+        assert_eq!(threading_infeed_constant_area(0.005, 0.01), vec![0.005]);
+        assert!(threading_infeed_constant_area(0.03, 0.0).is_empty());
+        assert!(threading_infeed_constant_area(f64::NAN, 0.01).is_empty());
+        assert!(threading_infeed_constant_area(0.03, f64::NAN).is_empty());
+        assert!(threading_infeed_constant_area(f64::INFINITY, 0.01).is_empty());
+    }
+}
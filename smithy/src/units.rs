@@ -0,0 +1,228 @@
+use crate::layout::Coord;
+
+/// Millimeters per inch (exact by definition).
+pub const MM_PER_IN: f64 = 25.4;
+
+/// Length units for coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Inch,
+    Millimeter,
+}
+
+impl Unit {
+    /// Returns the length of one unit in millimeters.
+    fn mm(self) -> f64 {
+        match self {
+            Unit::Inch => MM_PER_IN,
+            Unit::Millimeter => 1.0,
+        }
+    }
+}
+
+/// Converts millimeters to inches.
+///
+/// # Example
+/// ```rust
+/// use smithy::units::mm_to_in;
+/// assert_eq!(mm_to_in(25.4), 1.0);
+/// ```
+pub fn mm_to_in(mm: f64) -> f64 {
+    mm / MM_PER_IN
+}
+
+/// Converts inches to millimeters.
+///
+/// # Example
+/// ```rust
+/// use smithy::units::in_to_mm;
+/// assert_eq!(in_to_mm(0.5), 12.7);
+/// ```
+pub fn in_to_mm(inches: f64) -> f64 {
+    inches * MM_PER_IN
+}
+
+/// Converts thousandths of an inch to millimeters.
+///
+/// # Example
+/// ```rust
+/// use smithy::units::thou_to_mm;
+/// assert_eq!(thou_to_mm(10.0), 0.254);
+/// ```
+pub fn thou_to_mm(thou: f64) -> f64 {
+    in_to_mm(thou / 1000.0)
+}
+
+/// Converts a point between inch and metric units.
+///
+/// x, y and z are scaled; the angle is in degrees and is left unchanged.
+///
+/// # Parameters
+/// - c: The point to convert.
+/// - from: The units of `c`.
+/// - to: The units to convert to.
+///
+/// # Returns
+/// - `Coord`: The converted point.
+///
+/// # Example
+/// ```rust
+/// use smithy::layout::Coord;
+/// use smithy::units::{convert_coord, Unit};
+/// let c = convert_coord(Coord::new(25.4, 50.8), Unit::Millimeter, Unit::Inch);
+/// assert_eq!((c.x, c.y), (1.0, 2.0));
+/// ```
+pub fn convert_coord(c: Coord, from: Unit, to: Unit) -> Coord {
+    if from == to {
+        return c;
+    }
+    // Multiply before dividing so exact conversions like 25.4 mm to 1" stay exact.
+    let conv = |v: f64| v * from.mm() / to.mm();
+    Coord {
+        x: conv(c.x),
+        y: conv(c.y),
+        z: c.z.map(conv),
+        angle: c.angle,
+    }
+}
+
+/// Formats a length in inches as a fraction, e.g. 0.375 as `3/8` and 1.625 as `1-5/8`.
+///
+/// The value is rounded to the nearest `1/denominator` and the fraction is reduced, so
+/// 0.5 in 64ths is `1/2`. Whole numbers have no fraction and values that round to zero
+/// give `0`. Negative values start with `-`.
+///
+/// # Parameters
+/// - value: The length, in inches.
+/// - denominator: The finest fraction to round to, e.g. 64 for 1/64". Zero is treated as
+///   1, rounding to whole inches.
+///
+/// # Returns
+/// - `String`: The fraction, with any whole inches and the fraction joined by `-`.
+///
+/// # Example
+/// ```rust
+/// use smithy::units::to_fraction;
+/// assert_eq!(to_fraction(1.625, 64), "1-5/8");
+/// ```
+#[cfg(feature = "std")]
+pub fn to_fraction(value: f64, denominator: u32) -> String {
+    let den = denominator.max(1) as u64;
+    let n = crate::float::round(value.abs() * den as f64) as u64;
+    let (whole, rem) = (n / den, n % den);
+    let sign = if value < 0.0 && n > 0 { "-" } else { "" };
+    if rem == 0 {
+        return format!("{sign}{whole}");
+    }
+    let gcd = {
+        let (mut a, mut b) = (rem, den);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    let frac = format!("{}/{}", rem / gcd, den / gcd);
+    if whole == 0 {
+        format!("{sign}{frac}")
+    } else {
+        format!("{sign}{whole}-{frac}")
+    }
+}
+
+/// Formats a point in inches with fractions, like `Display` but using `to_fraction` for
+/// every value, e.g. `(1-1/2, 3/8)`.
+///
+/// The angle is in degrees rather than inches and is left out.
+///
+/// # Parameters
+/// - c: The point to format, in inches.
+/// - denominator: The finest fraction to round to, e.g. 64 for 1/64".
+///
+/// # Returns
+/// - `String`: The point as `(x, y)`, or `(x, y, z)` when it has a depth.
+///
+/// # Example
+/// ```rust
+/// use smithy::layout::Coord;
+/// use smithy::units::coord_to_fraction;
+/// assert_eq!(coord_to_fraction(&Coord::new(1.5, 0.375), 64), "(1-1/2, 3/8)");
+/// ```
+#[cfg(feature = "std")]
+pub fn coord_to_fraction(c: &Coord, denominator: u32) -> String {
+    let frac = |v| to_fraction(v, denominator);
+    match c.z {
+        Some(z) => format!("({}, {}, {})", frac(c.x), frac(c.y), frac(z)),
+        None => format!("({}, {})", frac(c.x), frac(c.y)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(mm_to_in(25.4), 1.0);
+        assert_eq!(in_to_mm(mm_to_in(25.4)), 25.4);
+        assert_eq!(math::round(mm_to_in(6.0), 4), 0.2362);
+        assert_eq!(math::round(thou_to_mm(1.0), 4), 0.0254);
+    }
+
+    #[test]
+    fn test_convert_coord() {
+        let c = Coord {
+            x: 25.4,
+            y: -12.7,
+            z: Some(-6.35),
+            angle: Some(30.0),
+        };
+        let inch = convert_coord(c.clone(), Unit::Millimeter, Unit::Inch);
+        assert_eq!(
+            inch,
+            Coord {
+                x: 1.0,
+                y: -0.5,
+                z: Some(-0.25),
+                angle: Some(30.0),
+            }
+        );
+        assert_eq!(convert_coord(inch, Unit::Inch, Unit::Millimeter), c);
+        assert_eq!(convert_coord(c.clone(), Unit::Inch, Unit::Inch), c);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_to_fraction() {
+        assert_eq!(to_fraction(0.375, 64), "3/8");
+        assert_eq!(to_fraction(0.5, 64), "1/2");
+        assert_eq!(to_fraction(1.625, 64), "1-5/8");
+        assert_eq!(to_fraction(2.0, 64), "2");
+        assert_eq!(to_fraction(-0.75, 16), "-3/4");
+        assert_eq!(to_fraction(-1.0625, 16), "-1-1/16");
+
+        // 0.3 is 19.2 64ths.
+        assert_eq!(to_fraction(0.3, 64), "19/64");
+        assert_eq!(to_fraction(0.201, 64), "13/64");
+        // Rounding can carry into the whole inches, or down to zero.
+        assert_eq!(to_fraction(0.999, 64), "1");
+        assert_eq!(to_fraction(-0.001, 64), "0");
+        assert_eq!(to_fraction(2.4, 0), "2");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_coord_to_fraction() {
+        assert_eq!(
+            coord_to_fraction(&Coord::new(1.5, -0.375), 64),
+            "(1-1/2, -3/8)"
+        );
+        let c = Coord {
+            x: 0.0,
+            y: 2.25,
+            z: Some(-0.125),
+            angle: Some(45.0),
+        };
+        assert_eq!(coord_to_fraction(&c, 32), "(0, 2-1/4, -1/8)");
+    }
+}